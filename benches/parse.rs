@@ -0,0 +1,78 @@
+//! Rough timings of `parse_date` and `is_date` for typical inputs:
+//! `cargo bench`.
+
+use diligent_date_parser::chrono::{DateTime, FixedOffset};
+use diligent_date_parser::{is_date, parse_date};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench(name: &str, inputs: &[&str]) {
+    run(name, inputs, parse_date);
+}
+
+fn run<T>(name: &str, inputs: &[&str], parse: impl Fn(&str) -> T) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for input in inputs {
+            black_box(parse(black_box(input)));
+        }
+    }
+    let per_parse = start.elapsed() / (ITERATIONS * inputs.len() as u32);
+    println!("{:<20} {:>8?} per parse", name, per_parse);
+}
+
+/// Inputs starting with a letter skip the ISO 8601 formats; this runs them
+/// anyway before `parse_date`, as a baseline for what the skip saves on
+/// RFC2822 inputs.
+fn without_skip(string: &str) -> Option<DateTime<FixedOffset>> {
+    const ISO_8601: [&str; 6] = [
+        "%Y-%m-%dT%H:%M:%S%.f%z",
+        "%Y-%m-%d %H:%M:%S%.f %#z",
+        "%Y-%m-%d %H:%M %z",
+        "%Y-%m-%dT%H:%M %z",
+        "%Y%m%dT%H%M%S%z",
+        "%Y-%m-%dT%H%M%S%#z",
+    ];
+    DateTime::parse_from_rfc3339(string)
+        .ok()
+        .or_else(|| {
+            ISO_8601
+                .iter()
+                .find_map(|format| DateTime::parse_from_str(string, format).ok())
+        })
+        .or_else(|| parse_date(string))
+}
+
+fn main() {
+    bench(
+        "rfc3339",
+        &["2016-04-21T08:30:00Z", "2016-04-21T08:30:00.123+02:00"],
+    );
+    bench(
+        "rfc2822",
+        &[
+            "Fri, 12 Feb 2016 14:08:24 +0000",
+            "Fri, 14 Nov 2014 17:16:12 PST",
+            "Tue, 3 Jul 2012 23:02:36",
+        ],
+    );
+    run(
+        "rfc2822 without skip",
+        &[
+            "Fri, 12 Feb 2016 14:08:24 +0000",
+            "Fri, 14 Nov 2014 17:16:12 PST",
+            "Tue, 3 Jul 2012 23:02:36",
+        ],
+        without_skip,
+    );
+    bench("twitter", &["Sun Dec 24 13:19:25 +0200 2017"]);
+    bench("month name", &["April 21, 2016", "Apr 21 2016"]);
+    bench("invalid", &["Yesterday", "2016-13-01"]);
+    run(
+        "is_date",
+        &["2016-04-21T08:30:00Z", "2016-04-21T08:30:00.123+02:00"],
+        is_date,
+    );
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+use diligent_date_parser::{parse_date, DateParser};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(string) = std::str::from_utf8(data) {
+        let _ = parse_date(string);
+        let _ = DateParser::new().allow_english(true).try_parse(string);
+    }
+});
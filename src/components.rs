@@ -0,0 +1,219 @@
+use crate::{is_digits, is_numeric_offset, zones};
+use chrono::{offset::FixedOffset, DateTime, Month, NaiveDate};
+
+/// A parsed date together with details of how it was written.
+///
+/// Returned by [`parse_date_components`](crate::parse_date_components).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateComponents<'a> {
+    pub(crate) datetime: DateTime<FixedOffset>,
+    pub(crate) offset: Option<&'a str>,
+}
+
+impl<'a> DateComponents<'a> {
+    /// The parsed date, the same value [`parse_date`](crate::parse_date)
+    /// returns.
+    pub fn datetime(&self) -> DateTime<FixedOffset> {
+        self.datetime
+    }
+
+    /// The zone exactly as it was written in the input: `Z`, `+00:00`,
+    /// `-00:00`, `+0530`, `GMT`, etc. `None` when the input had no zone and
+    /// UTC was assumed, or when the zone it had was not the one parsed.
+    ///
+    /// All of `Z`, `+00:00` and `-00:00` yield the same offset, so this is
+    /// the only way to tell them apart, e.g. to write the value back in the
+    /// form it came in.
+    pub fn offset_token(&self) -> Option<&'a str> {
+        self.offset
+    }
+}
+
+/// A date that may lack some of its parts, see
+/// [`parse_partial`](crate::parse_partial). More precisions may be added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PartialDate {
+    /// Only a year: `2016`.
+    Year(i32),
+    /// A year and a month: `2016-04`, `April 2016`.
+    YearMonth(i32, Month),
+    /// A date without a time of day: `2016-04-21`, `April 21, 2016`.
+    Date(NaiveDate),
+    /// A date with a time of day, as [`parse_date`](crate::parse_date)
+    /// returns it.
+    DateTime(DateTime<FixedOffset>),
+}
+
+/// Parses a bare year (`2016`) or a year and a month (`2016-04`,
+/// `April 2016`, `Apr 2016`).
+pub(crate) fn year_month(string: &str) -> Option<PartialDate> {
+    let year = |year: &str| match year.len() == 4 && is_digits(year) {
+        true => year.parse().ok(),
+        false => None,
+    };
+    if let Some(year) = year(string) {
+        return Some(PartialDate::Year(year));
+    }
+    let (year, month) = match string.split_once(['-', ' ']) {
+        Some((first, month)) if first.len() == 4 => {
+            let month: u8 = month.parse().ok().filter(|_| month.len() == 2)?;
+            (year(first)?, Month::try_from(month).ok()?)
+        }
+        Some((month, last)) => (year(last.trim_start())?, month.parse().ok()?),
+        None => return None,
+    };
+    Some(PartialDate::YearMonth(year, month))
+}
+
+/// The numeric offset or `Z` at the end of a word (`08:30:00+02:00`,
+/// `2016-04-21T08:30:00Z`) or making up the whole of it (`+0200`).
+fn trailing_offset(word: &str) -> Option<&str> {
+    if is_numeric_offset(&word.replace('\u{2212}', "-")) {
+        return Some(word);
+    }
+    let bytes = word.as_bytes();
+    if bytes.len() >= 2
+        && matches!(bytes[bytes.len() - 1], b'Z' | b'z')
+        && bytes[bytes.len() - 2].is_ascii_digit()
+    {
+        return Some(&word[word.len() - 1..]);
+    }
+    [6, 5, 8, 7].iter().find_map(|&len| {
+        let start = word.len().checked_sub(len)?;
+        let offset = word.get(start..)?;
+        let datetime = &word[..start];
+        let attached = datetime.ends_with(|c: char| c.is_ascii_digit());
+        let numeric = is_numeric_offset(&offset.replace('\u{2212}', "-"));
+        // A date like `2016-04-21` never has the four digits an offset needs,
+        // only a time does, extended (`08:30:00`) or basic (`T083000`)
+        let basic_time = datetime.rsplit_once(['T', 't']).is_some_and(|(_, time)| {
+            !time.is_empty() && time.bytes().all(|b| b.is_ascii_digit() || b == b'.')
+        });
+        (attached && numeric && (datetime.contains(':') || basic_time)).then_some(offset)
+    })
+}
+
+/// Finds the zone in an input that parsed successfully. A numeric offset
+/// wins over a zone name, like it does when parsing.
+pub(crate) fn offset_token(string: &str) -> Option<&str> {
+    let string = string.trim();
+    if string.starts_with('@') || string.starts_with('-') {
+        return None;
+    }
+    let words = || string.split_whitespace().rev();
+    None.or_else(|| words().find_map(trailing_offset))
+        .or_else(|| words().find(|word| zones::is_zone_name(word)))
+}
+
+/// Finds the zone that gave a parsed date its offset, see [`offset_token`].
+/// `None` if the zone found stands for another offset, as happens when the
+/// parse ignored it.
+pub(crate) fn parsed_offset_token(string: &str, offset: FixedOffset) -> Option<&str> {
+    let token = offset_token(string)?;
+    let token_offset = match token {
+        "Z" | "z" => FixedOffset::east_opt(0),
+        _ => zones::signed_offset(&token.replace('\u{2212}', "-"))
+            .or_else(|| zones::zone_offset(token)),
+    };
+    (token_offset == Some(offset)).then_some(token)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_year_month() {
+        assert_eq!(year_month("2016"), Some(PartialDate::Year(2016)));
+        assert_eq!(
+            year_month("2016-04"),
+            Some(PartialDate::YearMonth(2016, Month::April))
+        );
+        assert_eq!(
+            year_month("April 2016"),
+            Some(PartialDate::YearMonth(2016, Month::April))
+        );
+        assert_eq!(
+            year_month("dec 2016"),
+            Some(PartialDate::YearMonth(2016, Month::December))
+        );
+        assert_eq!(year_month("2016-13"), None);
+        assert_eq!(year_month("2016-4"), None);
+        assert_eq!(year_month("16"), None);
+        assert_eq!(year_month("2016-04-21"), None);
+        assert_eq!(year_month("April"), None);
+    }
+
+    #[test]
+    fn test_offset_token() {
+        assert_eq!(offset_token("2016-04-21T08:30:00Z"), Some("Z"));
+        assert_eq!(offset_token("2016-04-21T08:30:00z"), Some("z"));
+        assert_eq!(offset_token("2016-04-21T08:30:00+00:00"), Some("+00:00"));
+        assert_eq!(offset_token("2016-04-21T08:30:00-00:00"), Some("-00:00"));
+        assert_eq!(offset_token("2016-04-21T08:30:00.5+0530"), Some("+0530"));
+        assert_eq!(
+            offset_token("2016-04-21T08:30:00\u{2212}02:00"),
+            Some("\u{2212}02:00")
+        );
+        assert_eq!(offset_token("2016-04-21 +02:00"), Some("+02:00"));
+        assert_eq!(offset_token("20160421T083000+0200"), Some("+0200"));
+        assert_eq!(offset_token("2016-04-21T083000+0200"), Some("+0200"));
+        assert_eq!(offset_token("2016-04-21T083000.5-0530"), Some("-0530"));
+        assert_eq!(
+            offset_token("Fri, 12 Feb 2016 14:08:24 -0000"),
+            Some("-0000")
+        );
+        assert_eq!(offset_token("Fri, 12 Feb 2016 14:08:24 GMT"), Some("GMT"));
+        assert_eq!(
+            offset_token("Fri, 12 Feb 2016 14:08:24 +0100 (MEZ)"),
+            Some("+0100")
+        );
+        assert_eq!(
+            offset_token("Fri, 12 Feb 2016 14:08:24 +0000 America/New_York"),
+            Some("+0000")
+        );
+        assert_eq!(
+            offset_token("Sun Dec 24 13:19:25 +0200 2017"),
+            Some("+0200")
+        );
+        assert_eq!(
+            offset_token("2016-04-21 08:30:00 Etc/GMT+5"),
+            Some("Etc/GMT+5")
+        );
+        assert_eq!(offset_token("2016-04-21"), None);
+        assert_eq!(offset_token("2016-04-21T08:30:00"), None);
+        assert_eq!(offset_token("Tue, 3 Jul 2012 23:02:36"), None);
+        assert_eq!(offset_token("April 21, 2016"), None);
+        assert_eq!(offset_token("@1451606400"), None);
+    }
+
+    #[test]
+    fn test_parsed_offset_token() {
+        let hours = |hours| FixedOffset::east_opt(hours * 3600).unwrap();
+        assert_eq!(
+            parsed_offset_token("2016-04-21T08:30:00+02:00", hours(2)),
+            Some("+02:00")
+        );
+        assert_eq!(
+            parsed_offset_token(
+                "2016-04-21T08:30:00\u{2212}0530",
+                FixedOffset::east_opt(-19800).unwrap()
+            ),
+            Some("\u{2212}0530")
+        );
+        assert_eq!(
+            parsed_offset_token("2016-04-21T08:30:00Z", hours(0)),
+            Some("Z")
+        );
+        assert_eq!(
+            parsed_offset_token("Fri, 12 Feb 2016 14:08:24 EST", hours(-5)),
+            Some("EST")
+        );
+        assert_eq!(
+            parsed_offset_token("2016-04-21T08:30:00+02:00 extra", hours(0)),
+            None
+        );
+        assert_eq!(parsed_offset_token("2016-04-21T08:30:00", hours(0)), None);
+    }
+}
@@ -0,0 +1,369 @@
+use crate::{zones, WeekNumbering};
+use chrono::prelude::*;
+use chrono::{Duration, Months};
+
+fn year(string: &str) -> Option<i32> {
+    if string.len() == 4 && string.bytes().all(|b| b.is_ascii_digit()) {
+        string.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parses `start of <month> <year>` as the first day of the month at
+/// midnight and `end of <month> <year>` as its last day at 23:59:59. Without
+/// a month the phrase refers to the whole year.
+pub(crate) fn period_boundary(string: &str) -> Option<DateTime<FixedOffset>> {
+    let lower = string.to_ascii_lowercase();
+    let (end, period) = match lower.split_once(" of ")? {
+        ("start", period) => (false, period),
+        ("end", period) => (true, period),
+        _ => return None,
+    };
+    let words: Vec<&str> = period.split_whitespace().collect();
+    let (first_month, last_month, year) = match words[..] {
+        [year_word] => (1, 12, year(year_word)?),
+        [month, year_word] => {
+            let month = month.parse::<Month>().ok()?.number_from_month();
+            (month, month, year(year_word)?)
+        }
+        _ => return None,
+    };
+    let datetime = if end {
+        let next_month =
+            NaiveDate::from_ymd_opt(year, last_month, 1)?.checked_add_months(Months::new(1))?;
+        next_month.pred_opt()?.and_hms_opt(23, 59, 59)?
+    } else {
+        NaiveDate::from_ymd_opt(year, first_month, 1)?.and_time(NaiveTime::MIN)
+    };
+    Some(Utc.from_utc_datetime(&datetime).into())
+}
+
+/// Parses a range of days within a month, like `April 21–23, 2016`, into
+/// its first and last day at midnight. The days may be separated by an en
+/// dash, an em dash or a hyphen.
+pub(crate) fn day_range(string: &str) -> Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    let (month, rest) = string.split_once(char::is_whitespace)?;
+    let month = month.parse::<Month>().ok()?.number_from_month();
+    let (days, year_word) = rest.trim().rsplit_once(char::is_whitespace)?;
+    let (first, last) = days
+        .trim_end_matches(',')
+        .split_once(['\u{2013}', '\u{2014}', '-'])?;
+    let year = year(year_word)?;
+    let day = |day: &str| {
+        let day = day.trim().parse().ok()?;
+        let date = NaiveDate::from_ymd_opt(year, month, day)?;
+        Some(DateTime::from(
+            Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN)),
+        ))
+    };
+    let (first, last) = (day(first)?, day(last)?);
+    (first < last).then_some((first, last))
+}
+
+/// Parses a date written out in British style, like
+/// `Monday the 21st of April 2016`, as midnight UTC. The weekday, `the`, the
+/// ordinal suffix and `of` are all optional. The weekday is ignored, even
+/// when it does not match the date, as in the example.
+pub(crate) fn day_of_month(string: &str) -> Option<DateTime<FixedOffset>> {
+    let lower = string.to_lowercase();
+    let mut words: &[&str] = &lower
+        .split_whitespace()
+        .map(|word| word.trim_end_matches(','))
+        .collect::<Vec<_>>();
+    if words.first()?.parse::<Weekday>().is_ok() {
+        words = &words[1..];
+    }
+    if words.first() == Some(&"the") {
+        words = &words[1..];
+    }
+    let (day, month, year_word) = match words {
+        [day, "of", month, year] | [day, month, year] => (*day, *month, *year),
+        _ => return None,
+    };
+    let day = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| day.strip_suffix(suffix))
+        .unwrap_or(day);
+    if !(1..=2).contains(&day.len()) || !day.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let month = month.parse::<Month>().ok()?.number_from_month();
+    let date = NaiveDate::from_ymd_opt(year(year_word)?, month, day.parse().ok()?)?;
+    Some(Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN)).into())
+}
+
+/// Parses a week date with the weekday spelled out, like `2016 W15 Friday`
+/// for `2016-W15-5`, as midnight UTC.
+pub(crate) fn week_and_weekday(
+    string: &str,
+    weeks: WeekNumbering,
+) -> Option<DateTime<FixedOffset>> {
+    let words: Vec<&str> = string.split_whitespace().collect();
+    let (year_word, week, weekday) = match words[..] {
+        [year, week, weekday] => (year, week, weekday),
+        _ => return None,
+    };
+    let week = week.strip_prefix(['W', 'w'])?;
+    if week.len() != 2 || !week.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let day = weekday.parse::<Weekday>().ok()?.number_from_monday();
+    let date = weeks.date(year(year_word)?, week.parse().ok()?, day)?;
+    Some(Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN)).into())
+}
+
+/// Parses a date followed by a spelled out zone name, as Windows and Office
+/// write it: `Friday, April 21, 2016 8:30:00 AM Eastern Standard Time`. A
+/// leading weekday is dropped and the rest is parsed by `parse_date`, in
+/// the given zone.
+pub(crate) fn spelled_out_zone(
+    string: &str,
+    parse_date: impl Fn(&str) -> Option<DateTime<FixedOffset>>,
+) -> Option<DateTime<FixedOffset>> {
+    let (rest, offset) = zones::trailing_windows_zone(string)?;
+    let rest = match rest.split_once(' ') {
+        Some((weekday, rest)) if weekday.trim_end_matches(',').parse::<Weekday>().is_ok() => rest,
+        _ => rest,
+    };
+    let sign = if offset.local_minus_utc() < 0 {
+        '-'
+    } else {
+        '+'
+    };
+    let minutes = offset.local_minus_utc().abs() / 60;
+    let zoned = format!("{} {}{:02}{:02}", rest, sign, minutes / 60, minutes % 60);
+    parse_date(&zoned)
+}
+
+/// Converts an hour on the 12-hour clock to the 24-hour clock.
+fn meridiem_hour(hour: u32, meridiem: &str) -> Option<u32> {
+    match (hour, meridiem) {
+        (1..=11, "am") => Some(hour),
+        (12, "am") => Some(0),
+        (1..=11, "pm") => Some(hour + 12),
+        (12, "pm") => Some(12),
+        _ => None,
+    }
+}
+
+/// Parses a full hour written as `8 o'clock` (or `oclock`, optionally
+/// followed by `am`/`pm`) before or after a date, e.g.
+/// `8 o'clock April 21, 2016`. The date is parsed by `parse_date`.
+pub(crate) fn oclock(
+    string: &str,
+    parse_date: impl Fn(&str) -> Option<DateTime<FixedOffset>>,
+) -> Option<DateTime<FixedOffset>> {
+    // Only the phrase is case insensitive, the date is passed on as written
+    let lower = string.to_lowercase();
+    let lower: Vec<&str> = lower.split_whitespace().collect();
+    let words: Vec<&str> = string.split_whitespace().collect();
+    let position = lower
+        .iter()
+        .position(|word| matches!(*word, "o'clock" | "o\u{2019}clock" | "oclock"))?;
+    let hour: u32 = words.get(position.checked_sub(1)?)?.parse().ok()?;
+    let (hour, end) = match lower.get(position + 1) {
+        Some(&meridiem) if meridiem == "am" || meridiem == "pm" => {
+            (meridiem_hour(hour, meridiem)?, position + 2)
+        }
+        _ if hour <= 23 => (hour, position + 1),
+        _ => return None,
+    };
+    let date = [&words[..position - 1], &words[end..]].concat().join(" ");
+    let date = parse_date(&date)?;
+    if date.time() != NaiveTime::MIN {
+        return None;
+    }
+    Some(date + Duration::hours(hour.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn utc(year: i32, mon: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<FixedOffset> {
+        Utc.with_ymd_and_hms(year, mon, day, hour, min, sec)
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_period_boundary() {
+        assert_eq!(
+            period_boundary("start of April 2016"),
+            Some(utc(2016, 4, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            period_boundary("end of April 2016"),
+            Some(utc(2016, 4, 30, 23, 59, 59))
+        );
+        assert_eq!(
+            period_boundary("End of Feb 2016"),
+            Some(utc(2016, 2, 29, 23, 59, 59))
+        );
+        assert_eq!(
+            period_boundary("end of february 2015"),
+            Some(utc(2015, 2, 28, 23, 59, 59))
+        );
+        assert_eq!(
+            period_boundary("start of 2016"),
+            Some(utc(2016, 1, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            period_boundary("end of 2016"),
+            Some(utc(2016, 12, 31, 23, 59, 59))
+        );
+        assert_eq!(
+            period_boundary("end of December 2016"),
+            Some(utc(2016, 12, 31, 23, 59, 59))
+        );
+        assert_eq!(period_boundary("middle of April 2016"), None);
+        assert_eq!(period_boundary("end of Smarch 2016"), None);
+        assert_eq!(period_boundary("end of April"), None);
+        assert_eq!(period_boundary("end of April 2016 08:30"), None);
+    }
+
+    #[test]
+    fn test_oclock() {
+        assert_eq!(
+            oclock("8 o'clock April 21, 2016", crate::parse_date),
+            Some(utc(2016, 4, 21, 8, 0, 0))
+        );
+        assert_eq!(
+            oclock("8 oclock PM April 21, 2016", crate::parse_date),
+            Some(utc(2016, 4, 21, 20, 0, 0))
+        );
+        assert_eq!(
+            oclock("April 21, 2016 12 o\u{2019}clock am", crate::parse_date),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(
+            oclock("12 o'clock pm 2016-04-21", crate::parse_date),
+            Some(utc(2016, 4, 21, 12, 0, 0))
+        );
+        assert_eq!(
+            oclock("17 o'clock 2016-04-21 +02:00", crate::parse_date),
+            Some(utc(2016, 4, 21, 15, 0, 0))
+        );
+        assert_eq!(
+            oclock("13 o'clock pm April 21, 2016", crate::parse_date),
+            None
+        );
+        assert_eq!(
+            oclock("8 O'Clock 2016-W15-6", crate::parse_date),
+            Some(utc(2016, 4, 16, 8, 0, 0))
+        );
+        assert_eq!(oclock("24 o'clock April 21, 2016", crate::parse_date), None);
+        assert_eq!(oclock("o'clock April 21, 2016", crate::parse_date), None);
+        assert_eq!(oclock("8 o'clock", crate::parse_date), None);
+        assert_eq!(
+            oclock("8 o'clock 2016-04-21T08:30:00Z", crate::parse_date),
+            None
+        );
+    }
+
+    #[test]
+    fn test_day_range() {
+        assert_eq!(
+            day_range("April 21\u{2013}23, 2016"),
+            Some((utc(2016, 4, 21, 0, 0, 0), utc(2016, 4, 23, 0, 0, 0)))
+        );
+        assert_eq!(
+            day_range("Apr 21-23 2016"),
+            Some((utc(2016, 4, 21, 0, 0, 0), utc(2016, 4, 23, 0, 0, 0)))
+        );
+        assert_eq!(
+            day_range("April 21 \u{2014} 23, 2016"),
+            Some((utc(2016, 4, 21, 0, 0, 0), utc(2016, 4, 23, 0, 0, 0)))
+        );
+        assert_eq!(day_range("April 23\u{2013}21, 2016"), None);
+        assert_eq!(day_range("February 28\u{2013}30, 2015"), None);
+        assert_eq!(day_range("April 21, 2016"), None);
+        assert_eq!(day_range("Smarch 21\u{2013}23, 2016"), None);
+    }
+
+    #[test]
+    fn test_day_of_month() {
+        assert_eq!(
+            day_of_month("Monday the 21st of April 2016"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(
+            day_of_month("the 1st of April 2016"),
+            Some(utc(2016, 4, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            day_of_month("Thursday, 21st April 2016"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(
+            day_of_month("22nd of Apr 2016"),
+            Some(utc(2016, 4, 22, 0, 0, 0))
+        );
+        assert_eq!(day_of_month("Monday the 21st of April 2016 08:30"), None);
+        assert_eq!(
+            day_of_month("Sunday the 21st of April 2016"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(day_of_month("Monday the 21st of"), None);
+        assert_eq!(day_of_month("the 31st of April 2016"), None);
+        assert_eq!(day_of_month("the 21st of Smarch 2016"), None);
+        assert_eq!(day_of_month("the 121st of April 2016"), None);
+    }
+
+    #[test]
+    fn test_spelled_out_zone() {
+        let eastern = FixedOffset::west_opt(5 * 3600).unwrap();
+        assert_eq!(
+            spelled_out_zone(
+                "Friday, April 21, 2016 8:30:00 AM Eastern Standard Time",
+                crate::parse_date
+            ),
+            Some(eastern.with_ymd_and_hms(2016, 4, 21, 8, 30, 0).unwrap())
+        );
+        let pacific = FixedOffset::west_opt(8 * 3600).unwrap();
+        assert_eq!(
+            spelled_out_zone(
+                "April 21, 2016 8:30 PM Pacific Standard Time",
+                crate::parse_date
+            ),
+            Some(pacific.with_ymd_and_hms(2016, 4, 21, 20, 30, 0).unwrap())
+        );
+        assert_eq!(
+            spelled_out_zone(
+                "2016-04-21 08:30:00 W. Europe Standard Time",
+                crate::parse_date
+            ),
+            crate::parse_date("2016-04-21T08:30:00+01:00")
+        );
+        assert_eq!(
+            spelled_out_zone("April 21, 2016 8:30 PM", crate::parse_date),
+            None
+        );
+        assert_eq!(
+            spelled_out_zone("Yesterday Eastern Standard Time", crate::parse_date),
+            None
+        );
+    }
+
+    #[test]
+    fn test_week_and_weekday() {
+        let iso = WeekNumbering::Iso;
+        assert_eq!(
+            week_and_weekday("2016 W15 Friday", iso),
+            Some(utc(2016, 4, 15, 0, 0, 0))
+        );
+        assert_eq!(
+            week_and_weekday("2016 w15 mon", iso),
+            Some(utc(2016, 4, 11, 0, 0, 0))
+        );
+        assert_eq!(
+            week_and_weekday("2016 W01 Sunday", WeekNumbering::UsCdc),
+            Some(utc(2016, 1, 9, 0, 0, 0))
+        );
+        assert_eq!(week_and_weekday("2016 W15 Friyay", iso), None);
+        assert_eq!(week_and_weekday("2016 W5 Friday", iso), None);
+        assert_eq!(week_and_weekday("2016 W54 Friday", iso), None);
+        assert_eq!(week_and_weekday("2016 15 Friday", iso), None);
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::format::ParseError;
+use std::error::Error;
+use std::fmt;
+
+/// An error returned by [`try_parse_date`](crate::try_parse_date).
+///
+/// More kinds of errors may be added, so matches need a wildcard arm.
+///
+/// ```rust
+/// # use diligent_date_parser::{try_parse_date, ParseDateError};
+/// let message = match try_parse_date("2016-13-01") {
+///     Ok(_) => "parsed".to_string(),
+///     Err(ParseDateError::NearMiss { hint, .. }) => hint,
+///     Err(error) => error.to_string(),
+/// };
+/// assert_eq!(message, "looked like ISO 8601 date but month 13 is invalid");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseDateError {
+    /// No known format matched. Carries the error of the format that came
+    /// closest to matching.
+    NoMatch(ParseError),
+    /// The input has the shape of a known format but holds an invalid value,
+    /// e.g. month 13. `hint` says which component is wrong.
+    #[non_exhaustive]
+    NearMiss { hint: String, source: ParseError },
+    /// The input was rejected by a check of the parser rather than by a
+    /// format, e.g. its year is outside of the
+    /// [`year_range`](crate::DateParser::year_range). `reason` says which.
+    #[non_exhaustive]
+    Rejected { reason: String },
+}
+
+impl ParseDateError {
+    /// Describes why an input that almost matched a format was rejected.
+    pub fn hint(&self) -> Option<&str> {
+        match self {
+            ParseDateError::NoMatch(_) => None,
+            ParseDateError::NearMiss { hint, .. } => Some(hint),
+            ParseDateError::Rejected { reason } => Some(reason),
+        }
+    }
+}
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDateError::NoMatch(source) => {
+                write!(f, "no known date format matched: {}", source)
+            }
+            ParseDateError::NearMiss { hint, .. } => f.write_str(hint),
+            ParseDateError::Rejected { reason } => f.write_str(reason),
+        }
+    }
+}
+
+impl Error for ParseDateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDateError::NoMatch(source) | ParseDateError::NearMiss { source, .. } => {
+                Some(source)
+            }
+            ParseDateError::Rejected { .. } => None,
+        }
+    }
+}
+
+/// Rejections have no [`ParseError`] of their own and convert into an
+/// invalid input error.
+impl From<ParseDateError> for ParseError {
+    fn from(error: ParseDateError) -> Self {
+        match error {
+            ParseDateError::NoMatch(source) | ParseDateError::NearMiss { source, .. } => source,
+            ParseDateError::Rejected { .. } => {
+                chrono::NaiveDate::parse_from_str("-", "x").expect_err("`-` is not `x`")
+            }
+        }
+    }
+}
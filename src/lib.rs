@@ -27,292 +27,2756 @@
 //! );
 //! ```
 
+mod components;
+mod english;
+mod error;
+mod locale;
+mod parser;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+mod weeks;
+mod zones;
+
 pub use chrono;
+use chrono::format::{ParseError, ParseErrorKind, ParseResult};
 use chrono::prelude::*;
 pub use chrono::{offset::FixedOffset, DateTime};
+pub use components::{DateComponents, PartialDate};
+pub use error::ParseDateError;
+pub use parser::{DateParser, Leniency};
+use std::borrow::Cow;
 use std::convert::AsRef;
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+pub use weeks::WeekNumbering;
+
+fn cut(string: &str, len: usize) -> Option<&str> {
+    if string.len() >= len && string.is_char_boundary(len) {
+        Some(&string[..len])
+    } else {
+        None
+    }
+}
+
+fn suffix(string: &str, suffix: &'static str) -> String {
+    format!("{}{}", string, suffix)
+}
+
+fn rfc3339<T: AsRef<str>>(string: T) -> ParseResult<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(string.as_ref())
+}
+
+fn rfc2822<T: AsRef<str>>(string: T) -> ParseResult<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc2822(string.as_ref())
+}
+
+fn utc_datetime(string: &str, format: &str) -> ParseResult<DateTime<FixedOffset>> {
+    NaiveDateTime::parse_from_str(string, format).map(|d| Utc.from_utc_datetime(&d).into())
+}
+
+fn utc_date(string: &str, format: &str, time: NaiveTime) -> ParseResult<DateTime<FixedOffset>> {
+    let date = NaiveDate::parse_from_str(string, format)?;
+    Ok(Utc.from_utc_datetime(&date.and_time(time)).into())
+}
+
+/// Moves a date parsed as midnight to another time of day on that date.
+fn at_time(datetime: DateTime<FixedOffset>, time: NaiveTime) -> DateTime<FixedOffset> {
+    let local = datetime.date_naive().and_time(time);
+    datetime
+        .offset()
+        .from_utc_datetime(&(local - *datetime.offset()))
+}
+
+/// Parses a date followed by a numeric offset (`2016-04-21 +02:00`).
+/// Since no time is given, the result is at `time` in that offset.
+fn offset_date(string: &str, format: &str, time: NaiveTime) -> ParseResult<DateTime<FixedOffset>> {
+    let (date, offset) = string.rsplit_once(' ').unwrap_or((string, ""));
+    let offset: FixedOffset = offset.parse()?;
+    let date = NaiveDate::parse_from_str(date.trim_end(), format)?;
+    let datetime = date.and_time(time) - offset;
+    Ok(offset.from_utc_datetime(&datetime))
+}
+
+pub(crate) fn is_digits(string: &str) -> bool {
+    !string.is_empty() && string.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parses seconds since the Unix epoch written after an `@` sigil
+/// (`@1451606400`, `@1451606400.5`), as accepted by GNU `date -d`.
+/// Negative timestamps (before 1970) are also accepted without the sigil,
+/// since nothing else starts with a minus sign (`-86400`).
+fn epoch(string: &str) -> Option<DateTime<FixedOffset>> {
+    let timestamp = match string.strip_prefix('@') {
+        Some(timestamp) => timestamp,
+        None if string.starts_with('-') => string,
+        None => return None,
+    };
+    let (negative, timestamp) = match timestamp.strip_prefix('-') {
+        Some(timestamp) => (true, timestamp),
+        None => (false, timestamp),
+    };
+    let (seconds, fraction) = match timestamp.split_once('.') {
+        Some((seconds, fraction)) if is_digits(fraction) && fraction.len() <= 9 => {
+            (seconds, fraction)
+        }
+        Some(_) => return None,
+        None => (timestamp, ""),
+    };
+    if !is_digits(seconds) {
+        return None;
+    }
+    let mut seconds: i64 = seconds.parse().ok()?;
+    let mut nanoseconds: u32 = format!("{:0<9}", fraction).parse().ok()?;
+    if negative {
+        seconds = -seconds;
+        if nanoseconds > 0 {
+            seconds -= 1;
+            nanoseconds = 1_000_000_000 - nanoseconds;
+        }
+    }
+    let datetime = Utc.timestamp_opt(seconds, nanoseconds).single()?;
+    Some(datetime.into())
+}
+
+fn is_iso_date(bytes: &[u8]) -> bool {
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+/// Whether the string is an RFC2822 date in its current syntax:
+/// `Fri, 12 Feb 2016 14:08:24 +0000`, where the weekday and seconds are
+/// optional. Words are separated by single spaces, the year has four digits
+/// and the zone is numeric or one of the named zones, `GMT` or the North
+/// American ones; military zones, two digit years and comments are
+/// rejected. The values themselves are left to chrono.
+fn is_strict_rfc2822(string: &str) -> bool {
+    const WEEKDAYS: [&str; 7] = ["Mon,", "Tue,", "Wed,", "Thu,", "Fri,", "Sat,", "Sun,"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    const ZONES: [&str; 10] = [
+        "UT", "GMT", "EST", "EDT", "CST", "CDT", "MST", "MDT", "PST", "PDT",
+    ];
+    let words: Vec<&str> = string.split(' ').collect();
+    let words = match words[..] {
+        [weekday, ref rest @ ..] if WEEKDAYS.contains(&weekday) => rest,
+        ref words => words,
+    };
+    let [day, month, year, time, zone] = words else {
+        return false;
+    };
+    let time = time.as_bytes();
+    let time_shape = matches!(time.len(), 5 | 8)
+        && time.iter().enumerate().all(|(i, b)| match i {
+            2 | 5 => *b == b':',
+            _ => b.is_ascii_digit(),
+        });
+    (1..=2).contains(&day.len())
+        && is_digits(day)
+        && MONTHS.contains(month)
+        && year.len() == 4
+        && is_digits(year)
+        && time_shape
+        && ((zone.len() == 5 && is_numeric_offset(zone)) || ZONES.contains(zone))
+}
+
+/// The year of a canonical RFC3339 date (`2016-04-21T08:30:00Z`,
+/// `2016-04-21T08:30:00.123+02:00`) with valid components, checked without
+/// building a `DateTime`. Seconds may go up to `max_second`, so that leap
+/// seconds can be left to chrono.
+pub(crate) fn canonical_rfc3339_year(string: &str, max_second: u32) -> Option<i32> {
+    let bytes = string.as_bytes();
+    if bytes.len() < 20 || !is_iso_date(&bytes[..10]) || bytes[10] != b'T' {
+        return None;
+    }
+    if bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+    let number = |start: usize, len: usize| {
+        bytes
+            .get(start..start + len)?
+            .iter()
+            .try_fold(0, |number, b| {
+                b.is_ascii_digit()
+                    .then(|| number * 10 + u32::from(b - b'0'))
+            })
+    };
+    if number(11, 2)? > 23 || number(14, 2)? > 59 || number(17, 2)? > max_second {
+        return None;
+    }
+    let mut zone = &bytes[19..];
+    if let [b'.', fraction @ ..] = zone {
+        let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        zone = &fraction[digits..];
+    }
+    match zone {
+        [b'Z'] => {}
+        [b'+' | b'-', _, _, b':', _, _] => {
+            let start = bytes.len() - 5;
+            if number(start, 2)? > 23 || number(start + 3, 2)? > 59 {
+                return None;
+            }
+        }
+        _ => return None,
+    }
+    let year = number(0, 4)? as i32;
+    NaiveDate::from_ymd_opt(year, number(5, 2)?, number(8, 2)?)?;
+    Some(year)
+}
+
+/// Whether an RFC2822-style date has a three digit year (`12 Feb 116`), as
+/// written by mail clients that took `getYear()` for the year.
+fn has_three_digit_year(string: &str) -> bool {
+    let words: Vec<&str> = string.split_whitespace().collect();
+    words.windows(3).any(|words| {
+        (1..=2).contains(&words[0].len())
+            && is_digits(words[0])
+            && words[1].len() == 3
+            && words[1].parse::<Month>().is_ok()
+            && words[2].len() == 3
+            && is_digits(words[2])
+    })
+}
+
+/// Inserts the `T` missing between a date and a time glued together
+/// (`2016-04-2108:30:00`).
+fn missing_time_separator(string: &str) -> Option<String> {
+    let bytes = string.as_bytes();
+    let glued = bytes.len() >= 15
+        && is_iso_date(&bytes[..10])
+        && bytes[10..12].iter().all(u8::is_ascii_digit)
+        && bytes[12] == b':';
+    glued.then(|| format!("{}T{}", &string[..10], &string[10..]))
+}
+
+/// Rewrites a decimal fraction of the hour (`2016-04-21T08.5`) or of the
+/// minute (`2016-04-21T08:30.5`) as minutes, seconds and nanoseconds
+/// (`2016-04-21T08:30:00.000000000`). The time may also follow a space,
+/// which is rewritten as `T`.
+/// Anything following the fraction, such as an offset, is kept. The result
+/// is truncated to whole nanoseconds.
+fn decimal_time_components(string: &str) -> Option<String> {
+    let bytes = string.as_bytes();
+    if bytes.len() < 15 || !is_iso_date(&bytes[..10]) || !matches!(bytes[10], b'T' | b' ') {
+        return None;
+    }
+    let (whole, unit) = match bytes[13] {
+        b'.' => (13, 3600),
+        b':' if bytes.len() > 17 && bytes[16] == b'.' => (16, 60),
+        _ => return None,
+    };
+    if !is_digits(&string[11..13]) || (unit == 60 && !is_digits(&string[14..16])) {
+        return None;
+    }
+    let digits = bytes[whole + 1..]
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    if digits == 0 {
+        return None;
+    }
+    // Enough digits for nanoseconds of an hour, few enough for a u128
+    let fraction = &string[whole + 1..whole + 1 + digits.min(20)];
+    let rest = &string[whole + 1 + digits..];
+    let scale = 10u128.pow(fraction.len() as u32);
+    let nanos = fraction.parse::<u128>().ok()? * unit * 1_000_000_000 / scale;
+    let (minute, nanos) = match unit {
+        60 => (string[14..16].parse().ok()?, nanos),
+        _ => (nanos / 60_000_000_000, nanos % 60_000_000_000),
+    };
+    Some(format!(
+        "{}T{}:{:02}:{:02}.{:09}{}",
+        &string[..10],
+        &string[11..13],
+        minute,
+        nanos / 1_000_000_000,
+        nanos % 1_000_000_000,
+        rest
+    ))
+}
+
+/// Splits a label (`Date: `, `Timestamp=`), a word of letters, digits and
+/// underscores followed by `:` or `=`, off the value it precedes.
+fn strip_label(string: &str) -> Option<(&str, &str)> {
+    let end = string.find([':', '='])?;
+    let label = &string[..end];
+    let word = !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '_');
+    word.then(|| (label, string[end + 1..].trim_start()))
+}
+
+/// Whether a label names seconds since the epoch, like `Timestamp` or
+/// `created_epoch`.
+fn is_epoch_label(label: &str) -> bool {
+    let label = label.to_ascii_lowercase();
+    label == "ts"
+        || ["epoch", "timestamp", "unix"]
+            .iter()
+            .any(|word| label.contains(word))
+}
+
+/// Rewrites a compact ISO 8601 date (`20160421`) with dashes.
+fn compact_date(string: &str) -> Option<String> {
+    if string.len() != 8 || !is_digits(string) {
+        return None;
+    }
+    let date = NaiveDate::parse_from_str(string, "%Y%m%d").ok()?;
+    Some(date.format("%Y-%m-%d").to_string())
+}
+
+/// Rewrites the ISO 8601 end of a day (`2016-04-21T24:00:00+02:00`) as the
+/// start of the next one (`2016-04-22T00:00:00+02:00`), keeping the offset
+/// with the rewritten date. Seconds and a fraction of zeros are optional.
+fn end_of_day(string: &str) -> Option<String> {
+    let bytes = string.as_bytes();
+    if bytes.len() < 16 || !is_iso_date(&bytes[..10]) || !matches!(bytes[10], b'T' | b' ') {
+        return None;
+    }
+    let rest = string[11..].strip_prefix("24:00")?;
+    let rest = rest.strip_prefix(":00").unwrap_or(rest);
+    let rest = match rest.strip_prefix('.') {
+        Some(fraction) if fraction.starts_with('0') => fraction.trim_start_matches('0'),
+        Some(_) => return None,
+        None => rest,
+    };
+    if rest.starts_with(|c: char| c.is_ascii_digit() || c == ':') {
+        return None;
+    }
+    let next = NaiveDate::parse_from_str(&string[..10], "%Y-%m-%d")
+        .ok()?
+        .succ_opt()?;
+    Some(format!(
+        "{}{}00:00:00{}",
+        next.format("%Y-%m-%d"),
+        bytes[10] as char,
+        rest
+    ))
+}
+
+/// Replaces an underscore between a date and a time (`2016-04-21_08:30:00`)
+/// with `T`.
+fn underscore_separator(string: &str) -> Option<String> {
+    let bytes = string.as_bytes();
+    if bytes.len() > 11
+        && bytes[10] == b'_'
+        && is_iso_date(&bytes[..10])
+        && bytes[11].is_ascii_digit()
+    {
+        Some(format!("{}T{}", &string[..10], &string[11..]))
+    } else {
+        None
+    }
+}
+
+/// Replaces a comma used as a decimal sign after the seconds (`08:30:00,5`)
+/// with a period, so that the fraction and anything following it (e.g. an
+/// offset) survive RFC3339 parsing.
+fn comma_decimal(string: &str) -> Option<String> {
+    let bytes = string.as_bytes();
+    let position = (8..bytes.len().saturating_sub(1)).find(|&i| {
+        bytes[i] == b','
+            && bytes[i - 3] == b':'
+            && bytes[i - 2].is_ascii_digit()
+            && bytes[i - 1].is_ascii_digit()
+            && bytes[i + 1].is_ascii_digit()
+    })?;
+    Some(format!(
+        "{}.{}",
+        &string[..position],
+        &string[position + 1..]
+    ))
+}
+
+/// Drops the periods from `a.m.` and `p.m.` (in either case), leaving the
+/// `am`/`pm` the 12-hour formats understand.
+fn dotted_meridiem(string: &str) -> Option<String> {
+    let dotted =
+        |word: &str| word.eq_ignore_ascii_case("a.m.") || word.eq_ignore_ascii_case("p.m.");
+    if !string.split(' ').any(dotted) {
+        return None;
+    }
+    let words: Vec<String> = string
+        .split(' ')
+        .map(|word| {
+            if dotted(word) {
+                word.replace('.', "")
+            } else {
+                word.to_owned()
+            }
+        })
+        .collect();
+    Some(words.join(" "))
+}
+
+/// Moves an `AM`/`PM` written before the time (`PM 8:30 April 21 2016`),
+/// together with the time, to the end, where the 12-hour formats expect
+/// them (`April 21 2016 8:30 PM`).
+fn leading_meridiem(string: &str) -> Option<String> {
+    let words: Vec<&str> = string.split_whitespace().collect();
+    let position = words.windows(2).position(|pair| {
+        let meridiem = pair[0].eq_ignore_ascii_case("am") || pair[0].eq_ignore_ascii_case("pm");
+        meridiem
+            && pair[1].starts_with(|c: char| c.is_ascii_digit())
+            && pair[1].contains([':', '.'])
+    })?;
+    let mut moved: Vec<&str> = words[..position].to_vec();
+    moved.extend_from_slice(&words[position + 2..]);
+    moved.extend_from_slice(&[words[position + 1], words[position]]);
+    Some(moved.join(" "))
+}
+
+/// Drops the colon from a trailing offset (`+05:30` to `+0530`), which is the
+/// only spelling RFC2822 allows.
+fn colonless_offset(string: &str) -> Option<String> {
+    let bytes = string.as_bytes();
+    let start = bytes.len().checked_sub(7)?;
+    let offset = &bytes[start..];
+    if offset[0] == b' '
+        && (offset[1] == b'+' || offset[1] == b'-')
+        && offset[4] == b':'
+        && [2, 3, 5, 6].iter().all(|&i| offset[i].is_ascii_digit())
+    {
+        Some(format!("{}{}", &string[..start + 4], &string[start + 5..]))
+    } else {
+        None
+    }
+}
+
+/// Adds missing seconds to a time followed by a zone
+/// (`14:08 +0000` to `14:08:00 +0000`).
+fn seconds_before_zone(string: &str) -> Option<String> {
+    let (datetime, zone) = string.rsplit_once(' ')?;
+    let time = datetime.rsplit(' ').next()?;
+    let (hours, minutes) = time.split_once(':')?;
+    if hours.len() == 2 && is_digits(hours) && minutes.len() == 2 && is_digits(minutes) {
+        Some(format!("{}:00 {}", datetime, zone))
+    } else {
+        None
+    }
+}
+
+pub(crate) fn is_numeric_offset(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    let digits = match bytes {
+        [b'+' | b'-', digits @ ..] => digits,
+        _ => return false,
+    };
+    match digits {
+        [a, b, b':', c, d] | [a, b, c, d] => [a, b, c, d].iter().all(|b| b.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+/// Whether the string contains a POSIX TZ rule such as `PST8PDT` or
+/// `CET-1CEST,M3.5.0`: an upper case zone abbreviation directly followed by
+/// an offset. Those offsets count hours *west* of UTC and the rest of the
+/// rule describes daylight saving time, so reading them as a date and a zone
+/// would produce garbage. `GMT+5` and `UTC-3` are zones of their own.
+/// Comments in parentheses, as RFC2822 allows after the zone, don't decide
+/// anything and are skipped.
+fn has_posix_tz(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    let mut start = 0;
+    let mut comments = 0usize;
+    while start < bytes.len() {
+        match bytes[start] {
+            b'(' => comments += 1,
+            b')' => comments = comments.saturating_sub(1),
+            _ => {}
+        }
+        if comments > 0 || !bytes[start].is_ascii_alphabetic() {
+            start += 1;
+            continue;
+        }
+        let end = bytes[start..]
+            .iter()
+            .position(|b| !b.is_ascii_alphabetic())
+            .map_or(bytes.len(), |len| start + len);
+        let letters = &string[start..end];
+        let offset = match bytes[end..] {
+            [b'+' | b'-', digit, ..] => {
+                digit.is_ascii_digit() && letters != "GMT" && letters != "UTC"
+            }
+            [digit, ..] => digit.is_ascii_digit(),
+            [] => false,
+        };
+        if offset
+            && letters.len() >= 3
+            && letters.bytes().all(|b| b.is_ascii_uppercase())
+            && letters.parse::<Month>().is_err()
+            && letters.parse::<Weekday>().is_err()
+        {
+            return true;
+        }
+        start = end;
+    }
+    false
+}
+
+fn is_iana_zone(string: &str) -> bool {
+    string.starts_with(|c: char| c.is_ascii_uppercase())
+        && string.contains('/')
+        && string.split('/').all(|part| {
+            !part.is_empty()
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b"_-+".contains(&b))
+        })
+}
+
+/// Drops an IANA zone name following a numeric offset, on its own
+/// (`+0000 America/New_York`) or attached to the time
+/// (`10:00:00-05:00 America/Chicago`), since the offset already fixes the
+/// instant.
+fn iana_zone_after_offset(string: &str) -> Option<String> {
+    let (datetime, zone) = string.rsplit_once(' ')?;
+    let word = datetime.rsplit(' ').next()?;
+    let attached = |len| {
+        let start = word.len().checked_sub(len)?;
+        let offset = word.get(start..)?;
+        let after_time = word[..start].ends_with(|c: char| c.is_ascii_digit());
+        (after_time && is_numeric_offset(offset)).then_some(offset)
+    };
+    let offset = match is_numeric_offset(word) {
+        true => Some(word),
+        false => attached(6).or_else(|| attached(5)),
+    };
+    if is_iana_zone(zone) && offset.is_some() {
+        Some(datetime.trim_end().to_string())
+    } else {
+        None
+    }
+}
+
+/// Replaces a trailing zone name (`PST`, `GMT+5`, `Etc/GMT+5`) with its numeric
+/// offset. See [`zones::zone_offset`] for the names understood.
+fn zone_name(string: &str) -> Option<String> {
+    let (datetime, zone) = string.rsplit_once(' ')?;
+    let offset = zones::zone_offset(zone)?.local_minus_utc();
+    let sign = if offset < 0 { '-' } else { '+' };
+    let minutes = offset.abs() / 60;
+    Some(format!(
+        "{} {}{:02}{:02}",
+        datetime.trim_end(),
+        sign,
+        minutes / 60,
+        minutes % 60
+    ))
+}
+
+/// Separates a month name from digits glued to it on either side
+/// (`Apr21 2016`, `3Jul2012`). Other letters, such as the `T` of ISO 8601,
+/// are left alone.
+fn glued_month(string: &str) -> Option<String> {
+    let bytes = string.as_bytes();
+    // (position, is a space needed before, is a space needed after)
+    let mut glued = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        if !bytes[start].is_ascii_alphabetic() {
+            start += 1;
+            continue;
+        }
+        let end = bytes[start..]
+            .iter()
+            .position(|b| !b.is_ascii_alphabetic())
+            .map_or(bytes.len(), |len| start + len);
+        let before = start > 0 && bytes[start - 1].is_ascii_digit();
+        let after = end < bytes.len() && bytes[end].is_ascii_digit();
+        if (before || after) && end - start >= 3 && string[start..end].parse::<Month>().is_ok() {
+            glued.push((start, end, before, after));
+        }
+        start = end;
+    }
+    if glued.is_empty() {
+        return None;
+    }
+    let mut result = String::with_capacity(string.len() + 2 * glued.len());
+    let mut copied = 0;
+    for (start, end, before, after) in glued {
+        result.push_str(&string[copied..start]);
+        if before {
+            result.push(' ');
+        }
+        result.push_str(&string[start..end]);
+        if after {
+            result.push(' ');
+        }
+        copied = end;
+    }
+    result.push_str(&string[copied..]);
+    Some(result)
+}
+
+/// Replaces a Unicode minus sign (U+2212) in front of an offset
+/// (`08:30:00−02:00`) with an ASCII hyphen. The offset has to follow a time
+/// or a space, so minus signs between date components are left alone.
+fn unicode_minus_offset(string: &str) -> Option<String> {
+    const MINUS: char = '\u{2212}';
+    let position = string.match_indices(MINUS).map(|(i, _)| i).find(|&i| {
+        let before = &string[..i];
+        let after = &string[i + MINUS.len_utf8()..];
+        let offset = after.split(char::is_whitespace).next().unwrap_or("");
+        let follows_time = before.ends_with(|c: char| c.is_ascii_digit()) && before.contains(':');
+        (follows_time || before.ends_with(' ')) && is_numeric_offset(&format!("-{}", offset))
+    })?;
+    Some(format!(
+        "{}-{}",
+        &string[..position],
+        &string[position + MINUS.len_utf8()..]
+    ))
+}
+
+/// Rewrites common deviations into a form the formats below understand.
+/// Each step returns `None` when it has nothing to change.
+fn normalize(string: &str) -> Cow<'_, str> {
+    let steps: [fn(&str) -> Option<String>; 7] = [
+        unicode_minus_offset,
+        underscore_separator,
+        comma_decimal,
+        dotted_meridiem,
+        iana_zone_after_offset,
+        zone_name,
+        glued_month,
+    ];
+    steps
+        .iter()
+        .fold(Cow::Borrowed(string), |string, step| match step(&string) {
+            Some(normalized) => Cow::Owned(normalized),
+            None => string,
+        })
+}
+
+/// How close a failed attempt came to matching: a value out of range means
+/// the input had the right shape, which beats an input of the wrong shape.
+fn closeness(error: &ParseError) -> u8 {
+    match error.kind() {
+        ParseErrorKind::OutOfRange | ParseErrorKind::Impossible => 3,
+        ParseErrorKind::NotEnough => 2,
+        ParseErrorKind::TooLong => 1,
+        _ => 0,
+    }
+}
+
+/// Collects errors while formats are tried one by one.
+#[derive(Default)]
+struct Attempts {
+    error: Option<ParseError>,
+    /// Whether to count the families attempted, which only a budget and
+    /// [`parse_date_debug`] need.
+    counting: bool,
+    families: usize,
+    /// How many families may be attempted, without limit if `None`.
+    budget: Option<usize>,
+    /// Why the input was rejected, when a check rather than a format did.
+    rejected: Option<String>,
+    /// The time of day given to inputs without one.
+    date_only_time: NaiveTime,
+}
+
+impl Attempts {
+    /// Tries a family of related formats, if it's enabled and the budget
+    /// allows, counting it as attempted if asked to.
+    fn family(
+        &mut self,
+        enabled: bool,
+        family: impl FnOnce(&mut Self) -> Option<DateTime<FixedOffset>>,
+    ) -> Option<DateTime<FixedOffset>> {
+        if !enabled {
+            return None;
+        }
+        if let Some(budget) = self.budget.filter(|budget| self.families >= *budget) {
+            return self.reject(format!(
+                "attempt budget of {} format families used up",
+                budget
+            ));
+        }
+        if self.counting {
+            self.families += 1;
+        }
+        family(self)
+    }
+
+    /// Records why the input was rejected, returning no date.
+    fn reject(&mut self, reason: String) -> Option<DateTime<FixedOffset>> {
+        self.rejected = Some(reason);
+        None
+    }
+
+    fn check(
+        &mut self,
+        result: ParseResult<DateTime<FixedOffset>>,
+    ) -> Option<DateTime<FixedOffset>> {
+        match result {
+            Ok(datetime) => Some(datetime),
+            Err(error) => {
+                match self.error {
+                    Some(e) if closeness(&e) >= closeness(&error) => {}
+                    _ => self.error = Some(error),
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Explains why an input shaped like an ISO 8601 date (`2016-13-01`) was
+/// rejected, naming the invalid component.
+fn near_miss(string: &str) -> Option<String> {
+    let date = cut(string, 10)?;
+    if !is_iso_date(date.as_bytes()) {
+        return None;
+    }
+    let year: i32 = date[..4].parse().ok()?;
+    let month: u32 = date[5..7].parse().ok()?;
+    let day: u32 = date[8..10].parse().ok()?;
+    let month_name = match Month::try_from(month as u8) {
+        Ok(month_name) => month_name.name(),
+        Err(_) => {
+            return Some(format!(
+                "looked like ISO 8601 date but month {} is invalid",
+                month
+            ))
+        }
+    };
+    if NaiveDate::from_ymd_opt(year, month, day).is_some() {
+        return None;
+    }
+    Some(format!(
+        "looked like ISO 8601 date but day {} is invalid in {} {}",
+        day, month_name, year
+    ))
+}
+
+/// Explains why an input shaped like an RFC2822 date
+/// (`Fri, 30 Feb 2016 14:08:24 +0000`) was rejected, naming the invalid
+/// day.
+fn rfc2822_near_miss(string: &str) -> Option<String> {
+    let mut words = string.split_whitespace().peekable();
+    words.next_if(|word| word.ends_with(','));
+    let (day, month, year) = (words.next()?, words.next()?, words.next()?);
+    if day.len() > 2 || !is_digits(day) || year.len() != 4 || !is_digits(year) {
+        return None;
+    }
+    let month = month.parse::<Month>().ok()?;
+    let day: u32 = day.parse().ok()?;
+    let year: i32 = year.parse().ok()?;
+    if NaiveDate::from_ymd_opt(year, month.number_from_month(), day).is_some() {
+        return None;
+    }
+    Some(format!(
+        "looked like RFC2822 date but day {} is invalid in {} {}",
+        day,
+        month.name(),
+        year
+    ))
+}
+
+/// RFC3339 and other ISO 8601 variants, all of which start with a digit.
+fn iso8601(string: &str, attempts: &mut Attempts) -> Option<DateTime<FixedOffset>> {
+    iso8601_exact(string, attempts).or_else(|| iso8601_prefix(string, attempts))
+}
+
+/// The ISO 8601 variants that must match the whole string.
+fn iso8601_exact(string: &str, attempts: &mut Attempts) -> Option<DateTime<FixedOffset>> {
+    let time = attempts.date_only_time;
+    let mut check = |result| attempts.check(result);
+    None.or_else(|| check(rfc3339(string)))
+        .or_else(|| check(DateTime::parse_from_str(string, "%Y-%m-%dT%H:%M:%S%.f%z")))
+        .or_else(|| check(DateTime::parse_from_str(string, "%Y-%m-%d %H:%M:%S%.f %#z")))
+        .or_else(|| check(DateTime::parse_from_str(string, "%Y-%m-%d %H:%M %z")))
+        .or_else(|| check(DateTime::parse_from_str(string, "%Y-%m-%dT%H:%M %z")))
+        .or_else(|| check(utc_datetime(string, "%Y-%m-%d %H:%M:%S%.3f")))
+        .or_else(|| check(utc_datetime(string, "%Y-%m-%dT%H:%M:%S%.f")))
+        .or_else(|| check(offset_date(string, "%Y-%m-%d", time)))
+        .or_else(|| check(DateTime::parse_from_str(string, "%Y%m%dT%H%M%S%z")))
+        .or_else(|| check(DateTime::parse_from_str(string, "%Y-%m-%dT%H%M%S%#z")))
+}
+
+/// ISO 8601 dates followed by something unknown, which is dropped along
+/// with whatever part of the time it is in.
+fn iso8601_prefix(string: &str, attempts: &mut Attempts) -> Option<DateTime<FixedOffset>> {
+    let time = attempts.date_only_time;
+    let mut check = |result| attempts.check(result);
+    None.or_else(|| cut(string, 20).and_then(|s| check(rfc3339(s))))
+        .or_else(|| cut(string, 19).and_then(|s| check(rfc3339(suffix(s, "Z")))))
+        .or_else(|| cut(string, 16).and_then(|s| check(rfc3339(suffix(s, ":00Z")))))
+        .or_else(|| cut(string, 13).and_then(|s| check(rfc3339(suffix(s, ":00:00Z")))))
+        .or_else(|| {
+            let date = cut(string, 10)?;
+            check(rfc3339(suffix(date, "T00:00:00Z"))).map(|datetime| at_time(datetime, time))
+        })
+}
+
+/// The year of an ISO 8601 date: four digits, or an expanded year of more
+/// digits with a sign (`+002016`, `-0044`).
+fn iso_year(string: &str) -> Option<i32> {
+    let digits = match string.as_bytes().first()? {
+        b'+' | b'-' => &string[1..],
+        _ if string.len() == 4 => string,
+        _ => return None,
+    };
+    if digits.len() < 4 || !is_digits(digits) {
+        return None;
+    }
+    string.parse().ok()
+}
+
+/// Parses a calendar date produced from a week or an ordinal date, along
+/// with the time that followed it.
+fn converted_date(
+    date: NaiveDate,
+    time: &str,
+    attempts: &mut Attempts,
+) -> Option<DateTime<FixedOffset>> {
+    iso8601(&format!("{}{}", date.format("%Y-%m-%d"), time), attempts)
+}
+
+/// Week dates (`2016-W15-5`, `2016-W15`, `+002016-W15-5`), which are
+/// converted to calendar dates according to `weeks` and then parsed like
+/// any other ISO 8601 date, including the time following them.
+fn week_date(
+    string: &str,
+    weeks: WeekNumbering,
+    attempts: &mut Attempts,
+) -> Option<DateTime<FixedOffset>> {
+    let (date, time) = string.split_at(string.find(['T', ' ']).unwrap_or(string.len()));
+    let (year, rest) = date.split_once("-W")?;
+    let (week, day) = rest.split_once('-').unwrap_or((rest, "1"));
+    if week.len() != 2 || day.len() != 1 || !is_digits(week) || !is_digits(day) {
+        return None;
+    }
+    let date = weeks.date(iso_year(year)?, week.parse().ok()?, day.parse().ok()?)?;
+    converted_date(date, time, attempts)
+}
+
+/// Ordinal dates (`2016-112`, `+002016-112`), converted to calendar dates
+/// like week dates are.
+fn ordinal_date(string: &str, attempts: &mut Attempts) -> Option<DateTime<FixedOffset>> {
+    let (date, time) = string.split_at(string.find(['T', ' ']).unwrap_or(string.len()));
+    let (year, day) = date.rsplit_once('-')?;
+    if day.len() != 3 || !is_digits(day) {
+        return None;
+    }
+    let date = NaiveDate::from_yo_opt(iso_year(year)?, day.parse().ok()?)?;
+    converted_date(date, time, attempts)
+}
+
+/// RFC2822 and variants of it missing the zone or parts of the time.
+fn rfc2822_variants(string: &str, attempts: &mut Attempts) -> Option<DateTime<FixedOffset>> {
+    let time = attempts.date_only_time;
+    let mut check = |result| attempts.check(result);
+    None.or_else(|| check(rfc2822(string)))
+        .or_else(|| colonless_offset(string).and_then(|s| check(rfc2822(s))))
+        .or_else(|| seconds_before_zone(string).and_then(|s| check(rfc2822(s))))
+        .or_else(|| check(rfc2822(suffix(string, " +0000"))))
+        .or_else(|| check(rfc2822(suffix(string, ":00 +0000"))))
+        .or_else(|| check(rfc2822(suffix(string, ":00:00 +0000"))))
+        .or_else(|| {
+            let datetime = check(rfc2822(suffix(string, " 00:00:00 +0000")))?;
+            Some(at_time(datetime, time))
+        })
+}
+
+/// The format of Twitter's API, and scraped variants of it with the year
+/// before the offset.
+fn twitter(string: &str, attempts: &mut Attempts) -> Option<DateTime<FixedOffset>> {
+    let mut check = |result| attempts.check(result);
+    None.or_else(|| check(DateTime::parse_from_str(string, "%a %b %d %H:%M:%S %z %Y")))
+        .or_else(|| check(DateTime::parse_from_str(string, "%a %b %d %H:%M:%S %Y %z")))
+}
+
+/// Splits a time of day (`08:30`, `8:30:15`, `8:30 PM`) off the end of a
+/// date. The hours and minutes may also be separated by a period
+/// (`8.30 PM`).
+fn trailing_time(string: &str) -> Option<(&str, NaiveTime)> {
+    let (rest, last) = string.rsplit_once(' ')?;
+    let (rest, clock, meridiem) =
+        if last.eq_ignore_ascii_case("am") || last.eq_ignore_ascii_case("pm") {
+            let (rest, clock) = rest.trim_end().rsplit_once(' ')?;
+            (rest, clock, Some(last))
+        } else {
+            (rest, last, None)
+        };
+    let clock = if clock.contains(':') {
+        Cow::Borrowed(clock)
+    } else {
+        Cow::Owned(clock.replacen('.', ":", 1))
+    };
+    let time = match meridiem {
+        Some(meridiem) => {
+            let clock = format!("{} {}", clock, meridiem);
+            NaiveTime::parse_from_str(&clock, "%I:%M %p")
+                .or_else(|_| NaiveTime::parse_from_str(&clock, "%I:%M:%S %p"))
+        }
+        None => NaiveTime::parse_from_str(&clock, "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(&clock, "%H:%M:%S")),
+    };
+    Some((rest.trim_end(), time.ok()?))
+}
+
+/// Dates with a spelled out month name, such as `April 21, 2016`,
+/// optionally followed by a time (`April 21, 2016 8:30 PM`) and a zone
+/// (`Mar 5 2016 CST`).
+fn month_name(string: &str, attempts: &mut Attempts) -> Option<DateTime<FixedOffset>> {
+    const FORMATS: [&str; 10] = [
+        "%b %d %Y",
+        "%b %e %Y",
+        "%B %d %Y",
+        "%B %e %Y",
+        "%b %d, %Y",
+        "%b %e, %Y",
+        "%B %d, %Y",
+        "%B %e, %Y",
+        "%d %B %Y",
+        "%e %B %Y",
+    ];
+    // A zone name has already been replaced by its offset in `zone_name`.
+    // The date needs a day of its own, or chrono reads `May 2016` as May 20
+    // of year 16.
+    let (string, offset) = match string.rsplit_once(' ') {
+        Some((date, offset))
+            if is_numeric_offset(offset) && date.split_whitespace().count() >= 3 =>
+        {
+            (date.trim_end(), offset.parse().ok()?)
+        }
+        _ => (string, Utc.fix()),
+    };
+    let (string, time) = trailing_time(string).unwrap_or((string, attempts.date_only_time));
+    FORMATS.iter().find_map(|format| {
+        let date = NaiveDate::parse_from_str(string, format);
+        attempts.check(date.map(|date| offset.from_utc_datetime(&(date.and_time(time) - offset))))
+    })
+}
+
+/// All-numeric dates other than ISO 8601.
+fn numeric(string: &str, attempts: &mut Attempts) -> Option<DateTime<FixedOffset>> {
+    let time = attempts.date_only_time;
+    let mut check = |result| attempts.check(result);
+    None.or_else(|| check(utc_date(string, "%m/%d/%Y", time)))
+        .or_else(|| check(utc_date(string, "%d.%m.%Y", time)))
+}
+
+fn parse(
+    parser: &DateParser,
+    string: &str,
+    attempts: &mut Attempts,
+) -> Option<DateTime<FixedOffset>> {
+    attempts.date_only_time = parser.date_only_time;
+    attempts.budget = parser.budget;
+    attempts.counting |= parser.budget.is_some();
+    let datetime = parse_formats(parser, string, attempts)?;
+    if !parser.in_year_range(&datetime) {
+        let (min, max) = parser.years;
+        return attempts.reject(format!(
+            "year {} is outside of the allowed range {}..={}",
+            datetime.year(),
+            min,
+            max
+        ));
+    }
+    Some(datetime)
+}
+
+fn parse_formats(
+    parser: &DateParser,
+    string: &str,
+    attempts: &mut Attempts,
+) -> Option<DateTime<FixedOffset>> {
+    if parser.strict {
+        return attempts.family(true, |a| {
+            if canonical_rfc3339_year(string, 60).is_some() {
+                a.check(rfc3339(string))
+            } else if is_strict_rfc2822(string) {
+                a.check(rfc2822(string))
+            } else {
+                a.reject("not exactly RFC3339 or RFC2822".to_string())
+            }
+        });
+    }
+    // Most inputs are canonical RFC3339, which needs neither trimming nor
+    // any of the normalization below, so try it before anything allocates.
+    if let Some(datetime) = attempts.family(true, |a| a.check(rfc3339(string))) {
+        return Some(datetime);
+    }
+    // chrono reads these the way RFC2822's obsolete syntax says, which is
+    // only wanted for archives known to have the getYear() bug
+    if !parser.three_digit_year && has_three_digit_year(string) {
+        return attempts.reject("three digit years need allow_three_digit_year".to_string());
+    }
+    let mut normalized = normalize(string.trim());
+    if let Some(separated) = missing_time_separator(&normalized) {
+        // Unless allowed, reject rather than misread the day as midnight
+        if !parser.missing_time_separator {
+            return attempts.reject(
+                "date is glued to its time, which needs allow_missing_time_separator".to_string(),
+            );
+        }
+        normalized = Cow::Owned(separated);
+    }
+    if parser.leading_meridiem {
+        if let Some(moved) = leading_meridiem(&normalized) {
+            normalized = Cow::Owned(moved);
+        }
+    }
+    let trimmed = normalized.as_ref();
+    if has_posix_tz(trimmed) {
+        return attempts.reject("POSIX TZ rules like `EST5EDT` are not supported".to_string());
+    }
+    // Translating only after the English formats failed keeps words both
+    // languages share, like German `Feb`, from breaking English dates. The
+    // cut ISO 8601 fallbacks wait for the translation though, or they would
+    // read `2016-04-21 um 08:30` as midnight.
+    let translated = locale::translate(trimmed).filter(|_| parser.localized);
+    families(parser, trimmed, translated.is_none(), attempts)
+        .or_else(|| families(parser, translated.as_deref()?, true, attempts))
+}
+
+/// Tries the format families in turn, common ones first.
+fn families(
+    parser: &DateParser,
+    trimmed: &str,
+    prefix: bool,
+    attempts: &mut Attempts,
+) -> Option<DateTime<FixedOffset>> {
+    // Inputs starting with a letter, typically a weekday in RFC2822 feeds,
+    // can't be ISO 8601 or numeric, so don't spend time on those formats.
+    let digits = !trimmed.starts_with(|c: char| c.is_ascii_alphabetic());
+    None.or_else(|| attempts.family(true, |_| epoch(trimmed)))
+        .or_else(|| {
+            attempts.family(digits, |a| {
+                let decimal = decimal_time_components(trimmed);
+                if decimal.is_some() && !parser.decimal_time {
+                    // Rather than misread `08.5` as 08:00
+                    return a.reject(
+                        "decimal fractions of hours or minutes need allow_decimal_time_components"
+                            .to_string(),
+                    );
+                }
+                if let Some(decimal) = decimal {
+                    // Out of range fractions must not fall back to a prefix
+                    return iso8601_exact(&decimal, a).or_else(|| {
+                        a.reject(format!("decimal time of {:?} is out of range", trimmed))
+                    });
+                }
+                let rewritten = end_of_day(trimmed);
+                let rewritten = rewritten.as_deref().unwrap_or(trimmed);
+                week_date(trimmed, parser.weeks, a)
+                    .or_else(|| ordinal_date(trimmed, a))
+                    .or_else(|| iso8601_exact(rewritten, a))
+                    .or_else(|| prefix.then(|| iso8601_prefix(rewritten, a)).flatten())
+            })
+        })
+        .or_else(|| attempts.family(true, |a| rfc2822_variants(trimmed, a)))
+        .or_else(|| attempts.family(true, |a| twitter(trimmed, a)))
+        .or_else(|| attempts.family(true, |a| month_name(trimmed, a)))
+        .or_else(|| attempts.family(digits, |a| numeric(trimmed, a)))
+        .or_else(|| attempts.family(parser.english, |_| english(parser, trimmed)))
+}
+
+/// Handles the phrases enabled by [`DateParser::allow_english`]. Dates
+/// inside a phrase are parsed with the same options as the phrase.
+fn english(parser: &DateParser, string: &str) -> Option<DateTime<FixedOffset>> {
+    let time = parser.date_only_time;
+    // `8 o'clock` is added to the day's midnight
+    let midnight = parser.date_only_time(NaiveTime::MIN);
+    None.or_else(|| english::period_boundary(string))
+        .or_else(|| english::week_and_weekday(string, parser.weeks).map(|date| at_time(date, time)))
+        .or_else(|| english::day_range(string).map(|(first, _)| at_time(first, time)))
+        .or_else(|| english::day_of_month(string).map(|date| at_time(date, time)))
+        .or_else(|| english::spelled_out_zone(string, |s| parser.parse(s)))
+        .or_else(|| english::oclock(string, |s| midnight.parse(s)))
+}
+
+fn try_parse(parser: &DateParser, string: &str) -> Result<DateTime<FixedOffset>, ParseDateError> {
+    let mut attempts = Attempts::default();
+    if let Some(datetime) = parse(parser, string, &mut attempts) {
+        return Ok(datetime);
+    }
+    if let Some(reason) = attempts.rejected {
+        return Err(ParseDateError::Rejected { reason });
+    }
+    let Some(source) = attempts.error else {
+        let reason = "no format was attempted".to_string();
+        return Err(ParseDateError::Rejected { reason });
+    };
+    let normalized = normalize(string.trim());
+    Err(
+        match near_miss(&normalized).or_else(|| rfc2822_near_miss(&normalized)) {
+            Some(hint) => ParseDateError::NearMiss { hint, source },
+            None => ParseDateError::NoMatch(source),
+        },
+    )
+}
+
+/// Parses a string using multiple formats
+///
+/// Canonical RFC3339 input is tried first, as is, so the most common case
+/// costs a single attempt without any allocation.
+///
+/// Inputs without a time of day are interpreted as midnight, in UTC
+/// unless an offset is given (`2016-04-21 +02:00` is midnight at +02:00).
+///
+/// Dates outside the years 1 to 9999 are rejected, see
+/// [`DateParser::year_range`].
+///
+/// A zone written as `GMT+5` or `UTC+5` is five hours *ahead* of UTC, as
+/// people usually mean it. Beware that the IANA name `Etc/GMT+5` follows the
+/// POSIX convention instead and is five hours *behind* UTC (`-05:00`); both
+/// are honored as written, so the two spellings give opposite offsets.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::parse_date;
+/// # use diligent_date_parser::chrono::prelude::*;
+/// # use diligent_date_parser::chrono::offset::FixedOffset;
+/// let datetime = parse_date("Mon, 2 Jan 2006 15:04:05 MST");
+/// let expected = FixedOffset::west(7 * 3600).ymd(2006, 1, 2).and_hms(15, 4, 5);
+/// assert_eq!(datetime, Some(expected));
+/// ```
+pub fn parse_date(string: &str) -> Option<DateTime<FixedOffset>> {
+    DateParser::new().parse(string)
+}
+
+/// Parses a string like [`parse_date`] and returns the instant in UTC and
+/// the offset the input was written in separately. Inputs without a zone
+/// get `+00:00`.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::parse_date_parts;
+/// use diligent_date_parser::chrono::prelude::*;
+///
+/// let (instant, offset) = parse_date_parts("2016-04-21T08:30:00+02:00").unwrap();
+/// assert_eq!(instant, Utc.with_ymd_and_hms(2016, 4, 21, 6, 30, 0).unwrap());
+/// assert_eq!(offset, FixedOffset::east_opt(7200).unwrap());
+/// ```
+pub fn parse_date_parts(string: &str) -> Option<(DateTime<Utc>, FixedOffset)> {
+    let datetime = parse_date(string)?;
+    Some((datetime.with_timezone(&Utc), *datetime.offset()))
+}
+
+/// Parses a string like [`parse_date`] and converts the instant into a
+/// [`SystemTime`]. Dates before 1970 are supported too; `None` is also
+/// returned when the platform's `SystemTime` can't represent the instant.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::parse_system_time;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!(
+///     parse_system_time("1970-01-02T00:00:00Z"),
+///     Some(UNIX_EPOCH + Duration::from_secs(86400)),
+/// );
+/// ```
+pub fn parse_system_time(string: &str) -> Option<SystemTime> {
+    let datetime = parse_date(string)?;
+    let nanos = StdDuration::from_nanos(datetime.timestamp_subsec_nanos().into());
+    let seconds = datetime.timestamp();
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(StdDuration::from_secs(seconds.unsigned_abs()) + nanos)
+    } else {
+        UNIX_EPOCH
+            .checked_sub(StdDuration::from_secs(seconds.unsigned_abs()))?
+            .checked_add(nanos)
+    }
+}
+
+/// Tells whether a string is a date, which is the same as
+/// `parse_date(string).is_some()`.
+///
+/// Canonical RFC3339 is validated without building a `DateTime`, which
+/// makes the check several times faster than parsing for such inputs (see
+/// `cargo bench`). Anything else is parsed as usual.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::is_date;
+/// assert!(is_date("2016-04-21T08:30:00Z"));
+/// assert!(is_date("April 21, 2016"));
+/// assert!(!is_date("2016-04-31T08:30:00Z"));
+/// ```
+pub fn is_date(string: &str) -> bool {
+    DateParser::new().is_date(string)
+}
+
+/// Parses RFC3339 and nothing else: `2016-04-21T08:30:00Z` or
+/// `2016-04-21T08:30:00.123+02:00`.
+///
+/// Unlike [`parse_date`], and unlike the leniency RFC3339 itself grants,
+/// this rejects surrounding whitespace, a lower case `t` or `z`, a space
+/// instead of `T`, missing seconds, offsets without a colon and anything
+/// else that is not exactly the `date-time` of RFC3339. It suits
+/// validating input that is required to conform.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::parse_rfc3339_strict;
+/// assert!(parse_rfc3339_strict("2016-04-21T08:30:00Z").is_some());
+/// assert!(parse_rfc3339_strict("2016-04-21t08:30:00z").is_none());
+/// assert!(parse_rfc3339_strict("2016-04-21T08:30Z").is_none());
+/// ```
+pub fn parse_rfc3339_strict(string: &str) -> Option<DateTime<FixedOffset>> {
+    canonical_rfc3339_year(string, 60)?;
+    rfc3339(string).ok()
+}
+
+/// Tells whether a string is exactly RFC3339, see [`parse_rfc3339_strict`].
+pub fn is_rfc3339(string: &str) -> bool {
+    parse_rfc3339_strict(string).is_some()
+}
+
+/// Parses a string that may specify only part of a date, keeping its
+/// precision instead of filling in the missing parts: `2016` is a
+/// [`PartialDate::Year`], `2016-04` and `April 2016` are a
+/// [`PartialDate::YearMonth`], date-only inputs are a [`PartialDate::Date`]
+/// and everything else [`parse_date`] accepts is a
+/// [`PartialDate::DateTime`].
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::{parse_partial, PartialDate};
+/// use diligent_date_parser::chrono::{Month, NaiveDate};
+///
+/// assert_eq!(parse_partial("2016"), Some(PartialDate::Year(2016)));
+/// assert_eq!(
+///     parse_partial("2016-04"),
+///     Some(PartialDate::YearMonth(2016, Month::April)),
+/// );
+/// assert_eq!(
+///     parse_partial("April 21, 2016"),
+///     Some(PartialDate::Date(NaiveDate::from_ymd_opt(2016, 4, 21).unwrap())),
+/// );
+/// ```
+pub fn parse_partial(string: &str) -> Option<PartialDate> {
+    DateParser::new().parse_partial(string)
+}
+
+/// Parses a string like [`parse_date`] and writes it back as RFC3339, so
+/// that differently formatted inputs for the same date compare equal. The
+/// offset is kept as parsed; convert the result of [`parse_date`] to
+/// [`Utc`] first to also fold together the same instant in different zones.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::canonicalize;
+/// assert_eq!(
+///     canonicalize("Thu, 21 Apr 2016 08:30:00 +0200").as_deref(),
+///     Some("2016-04-21T08:30:00+02:00"),
+/// );
+/// assert_eq!(canonicalize("not a date"), None);
+/// ```
+pub fn canonicalize(string: &str) -> Option<String> {
+    DateParser::new().canonicalize(string)
+}
+
+/// Parses a date preceded by a label, as in log lines and headers:
+/// `Date: 2016-04-21T08:30:00Z` or `Timestamp=1451606400`. The label is a
+/// word followed by `:` or `=`. After a label naming a timestamp
+/// (`Timestamp`, `epoch`, `unix_time`, `ts`) a number is read as seconds
+/// since the Unix epoch; after any other label it must be a compact date
+/// like `20160421`. Since a time like `08:30` also starts with a word and a
+/// colon, strings whose value doesn't parse are parsed whole, like
+/// [`parse_date`] does, and so are strings without a label.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::{parse_date, parse_labeled_date};
+/// assert_eq!(
+///     parse_labeled_date("Date: 2016-04-21T08:30:00Z"),
+///     parse_date("2016-04-21T08:30:00Z"),
+/// );
+/// assert_eq!(
+///     parse_labeled_date("Timestamp=1451606400"),
+///     parse_date("2016-01-01T00:00:00Z"),
+/// );
+/// ```
+pub fn parse_labeled_date(string: &str) -> Option<DateTime<FixedOffset>> {
+    DateParser::new().parse_labeled(string)
+}
+
+/// Parses a string like [`parse_date`] and also tells how the zone was
+/// written, see [`DateComponents`].
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::parse_date_components;
+/// let zulu = parse_date_components("2016-04-21T08:30:00Z").unwrap();
+/// let numeric = parse_date_components("2016-04-21T08:30:00-00:00").unwrap();
+/// assert_eq!(zulu.datetime(), numeric.datetime());
+/// assert_eq!(zulu.offset_token(), Some("Z"));
+/// assert_eq!(numeric.offset_token(), Some("-00:00"));
+/// ```
+pub fn parse_date_components(string: &str) -> Option<DateComponents<'_>> {
+    DateParser::new().parse_components(string)
+}
+
+/// Parses a string like [`parse_date`] and also returns how many format
+/// families (ISO 8601, RFC2822, month names, ...) were attempted before it
+/// succeeded or gave up.
+///
+/// This helps to find out which inputs of a corpus are expensive to parse.
+/// Only this function counts, so [`parse_date`] doesn't pay for it.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::parse_date_debug;
+/// let (datetime, families) = parse_date_debug("Fri, 12 Feb 2016 14:08:24 +0000");
+/// assert!(datetime.is_some());
+/// assert_eq!(families, 3);
+/// ```
+pub fn parse_date_debug(string: &str) -> (Option<DateTime<FixedOffset>>, usize) {
+    DateParser::new().parse_debug(string)
+}
+
+/// Parses a string using multiple formats, like [`parse_date`], but reports
+/// why the input was rejected when no format matches.
+///
+/// When the input has the shape of a known format but an invalid value
+/// (`2016-13-01`), the error is a [`ParseDateError::NearMiss`] naming the
+/// invalid component. When a check of the parser, such as its year range,
+/// rejected the input, it is a [`ParseDateError::Rejected`] saying which.
+/// Otherwise it carries the [`chrono::ParseError`] of the format that came
+/// closest to matching, and converts into one, so `?` works in functions
+/// returning [`chrono::ParseResult`].
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::try_parse_date;
+/// assert!(try_parse_date("2016-04-21").is_ok());
+/// let error = try_parse_date("2016-13-01").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "looked like ISO 8601 date but month 13 is invalid",
+/// );
+/// ```
+pub fn try_parse_date(string: &str) -> Result<DateTime<FixedOffset>, ParseDateError> {
+    DateParser::new().try_parse(string)
+}
+
+/// Parses a time of day: `08:30:00`, `08:30:00.250`, `08:30` or the
+/// compact `083000`.
+///
+/// Compact times are only accepted here, where no date is expected, since
+/// six digits could be a date just as well.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::parse_time;
+/// # use diligent_date_parser::chrono::NaiveTime;
+/// let expected = NaiveTime::from_hms_opt(8, 30, 0);
+/// assert_eq!(parse_time("08:30:00"), expected);
+/// assert_eq!(parse_time("083000"), expected);
+/// ```
+pub fn parse_time(string: &str) -> Option<NaiveTime> {
+    let trimmed = string.trim();
+    let compact = trimmed.len() == 6 && is_digits(trimmed);
+    None.or_else(|| NaiveTime::parse_from_str(trimmed, "%H:%M:%S%.f").ok())
+        .or_else(|| NaiveTime::parse_from_str(trimmed, "%H:%M").ok())
+        .or_else(|| compact.then(|| NaiveTime::parse_from_str(trimmed, "%H%M%S").ok())?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::Duration;
+
+    fn utc(year: i32, mon: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<FixedOffset> {
+        Utc.with_ymd_and_hms(year, mon, day, hour, min, sec)
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_comma_decimal() {
+        assert_eq!(
+            parse_date("2016-04-21T08:30:00,5+02:00"),
+            Some(
+                FixedOffset::east_opt(2 * 3600)
+                    .unwrap()
+                    .with_ymd_and_hms(2016, 4, 21, 8, 30, 0)
+                    .unwrap()
+                    + Duration::milliseconds(500)
+            )
+        );
+        assert_eq!(
+            parse_date("2016-04-21T08:30:00,5+02:00").map(|d| d.offset().local_minus_utc()),
+            Some(2 * 3600)
+        );
+        assert_eq!(
+            parse_date("2016-04-21T08:30:00,125Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0) + Duration::milliseconds(125))
+        );
+    }
+
+    #[test]
+    fn test_date_with_offset() {
+        let expected = FixedOffset::east_opt(2 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2016, 4, 21, 0, 0, 0)
+            .unwrap();
+        assert_eq!(parse_date("2016-04-21 +02:00"), Some(expected));
+        assert_eq!(parse_date("2016-04-21 +0200"), Some(expected));
+        assert_eq!(
+            parse_date("2016-04-21 +02:00"),
+            Some(utc(2016, 4, 20, 22, 0, 0))
+        );
+        assert_eq!(
+            parse_date("2016-04-21 +02:00").map(|d| *d.offset()),
+            Some(*expected.offset())
+        );
+    }
+
+    #[test]
+    fn test_space_padded_day() {
+        assert_eq!(parse_date("Apr  7, 2016"), Some(utc(2016, 4, 7, 0, 0, 0)));
+        assert_eq!(parse_date("Apr  7 2016"), Some(utc(2016, 4, 7, 0, 0, 0)));
+        assert_eq!(parse_date("April  7, 2016"), Some(utc(2016, 4, 7, 0, 0, 0)));
+        assert_eq!(parse_date("7  Apr 2016"), Some(utc(2016, 4, 7, 0, 0, 0)));
+        assert_eq!(parse_date("7 April 2016"), Some(utc(2016, 4, 7, 0, 0, 0)));
+        assert_eq!(parse_date("07 April 2016"), Some(utc(2016, 4, 7, 0, 0, 0)));
+        assert_eq!(parse_date(" 7  April 2016"), Some(utc(2016, 4, 7, 0, 0, 0)));
+        assert_eq!(parse_date("21 April 2016"), Some(utc(2016, 4, 21, 0, 0, 0)));
+    }
+
+    #[test]
+    fn test_try_parse_date() {
+        use chrono::format::ParseErrorKind;
+
+        fn year(string: &str) -> ParseResult<i32> {
+            Ok(try_parse_date(string)?.year())
+        }
+
+        assert_eq!(
+            try_parse_date("2014-01-11 01:18:21 +0000"),
+            Ok(utc(2014, 1, 11, 1, 18, 21))
+        );
+        assert_eq!(year("Fri, 12 Feb 2016 14:08:24 +0000"), Ok(2016));
+        assert_eq!(
+            try_parse_date("2016-13-01").map_err(|e| ParseError::from(e).kind()),
+            Err(ParseErrorKind::OutOfRange)
+        );
+        assert_eq!(
+            try_parse_date("2016-02-30").map_err(|e| ParseError::from(e).kind()),
+            Err(ParseErrorKind::OutOfRange)
+        );
+        assert!(try_parse_date("Yesterday").is_err());
+        assert!(try_parse_date("").is_err());
+    }
+
+    #[test]
+    fn test_underscore_separator() {
+        assert_eq!(
+            parse_date("2016-04-21_08:30:00"),
+            parse_date("2016-04-21T08:30:00")
+        );
+        assert_eq!(
+            parse_date("2016-04-21_08:30:00"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parse_date("2016-04-21_08:30:00+02:00"),
+            Some(utc(2016, 4, 21, 6, 30, 0))
+        );
+        assert_eq!(underscore_separator("2016-04-21_x"), None);
+        assert_eq!(underscore_separator("2016_04-21_08:30"), None);
+        assert_eq!(underscore_separator("Tue, 3 Jul 2012_23"), None);
+    }
+
+    #[test]
+    fn test_offset_spellings() {
+        let formats = [
+            "2016-04-21T08:30:00{}",
+            "2016-04-21T08:30:00.000{}",
+            "2016-04-21 08:30:00{}",
+            "2016-04-21 08:30:00 {}",
+            "Thu, 21 Apr 2016 08:30:00 {}",
+            "Thu Apr 21 08:30:00 {} 2016",
+        ];
+        let offsets = [
+            ("+05:30", "+0530", 5 * 3600 + 30 * 60),
+            ("+05:45", "+0545", 5 * 3600 + 45 * 60),
+            ("-05:30", "-0530", -(5 * 3600 + 30 * 60)),
+        ];
+        for format in formats {
+            for (with_colon, without_colon, seconds) in offsets {
+                let expected = FixedOffset::east_opt(seconds)
+                    .unwrap()
+                    .with_ymd_and_hms(2016, 4, 21, 8, 30, 0)
+                    .unwrap();
+                for offset in [with_colon, without_colon] {
+                    let input = format.replace("{}", offset);
+                    let actual = parse_date(&input);
+                    assert_eq!(actual, Some(expected), "{}", input);
+                    assert_eq!(
+                        actual.map(|d| *d.offset()),
+                        Some(*expected.offset()),
+                        "{}",
+                        input
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_epoch() {
+        assert_eq!(parse_date("@1451606400"), Some(utc(2016, 1, 1, 0, 0, 0)));
+        assert_eq!(parse_date(" @0 "), Some(utc(1970, 1, 1, 0, 0, 0)));
+        assert_eq!(
+            parse_date("@1451606400.5"),
+            Some(utc(2016, 1, 1, 0, 0, 0) + Duration::milliseconds(500))
+        );
+        assert_eq!(
+            parse_date("@1451606400.000000001"),
+            Some(utc(2016, 1, 1, 0, 0, 0) + Duration::nanoseconds(1))
+        );
+        assert_eq!(parse_date("@"), None);
+        assert_eq!(parse_date("@1451606400."), None);
+        assert_eq!(parse_date("@.5"), None);
+        assert_eq!(parse_date("@+1451606400"), None);
+        assert_eq!(parse_date("@1451606400.0000000001"), None);
+        assert_eq!(parse_date("@99999999999999999999"), None);
+    }
+
+    #[test]
+    fn test_near_miss() {
+        let hint = |string| try_parse_date(string).unwrap_err().hint().map(String::from);
+        assert_eq!(
+            hint("2016-13-01").as_deref(),
+            Some("looked like ISO 8601 date but month 13 is invalid")
+        );
+        assert_eq!(
+            hint("2016-02-30").as_deref(),
+            Some("looked like ISO 8601 date but day 30 is invalid in February 2016")
+        );
+        assert_eq!(
+            hint("2015-02-29T10:00:00Z").as_deref(),
+            Some("looked like ISO 8601 date but day 29 is invalid in February 2015")
+        );
+        assert_eq!(hint("Yesterday"), None);
+        assert!(matches!(
+            try_parse_date("Yesterday"),
+            Err(ParseDateError::NoMatch(_))
+        ));
+        assert_eq!(
+            try_parse_date("2016-13-01").unwrap_err().to_string(),
+            "looked like ISO 8601 date but month 13 is invalid"
+        );
+    }
+
+    #[test]
+    fn test_rejection_reason() {
+        let hint = |parser: DateParser, string| {
+            parser
+                .try_parse(string)
+                .unwrap_err()
+                .hint()
+                .map(String::from)
+        };
+        assert_eq!(
+            hint(DateParser::new(), "Fri, 30 Feb 2016 14:08:24 +0000").as_deref(),
+            Some("looked like RFC2822 date but day 30 is invalid in February 2016")
+        );
+        assert_eq!(
+            hint(DateParser::new().year_range(2000, 2099), "1999-04-21").as_deref(),
+            Some("year 1999 is outside of the allowed range 2000..=2099")
+        );
+        assert!(matches!(
+            try_parse_date("Fri, 12 Feb 116 14:08:24 +0000"),
+            Err(ParseDateError::Rejected { .. })
+        ));
+        assert!(matches!(
+            try_parse_date("2016-04-21 08:30:00 EST5EDT"),
+            Err(ParseDateError::Rejected { .. })
+        ));
+        assert!(matches!(
+            try_parse_date("2016-04-2108:30:00"),
+            Err(ParseDateError::Rejected { .. })
+        ));
+        assert_eq!(
+            hint(DateParser::new().attempt_budget(0), "2016-04-21").as_deref(),
+            Some("attempt budget of 0 format families used up")
+        );
+    }
+
+    #[test]
+    fn test_rfc2822_without_seconds() {
+        assert_eq!(
+            parse_date("Fri, 12 Feb 2016 14:08 +0000"),
+            Some(utc(2016, 2, 12, 14, 8, 0))
+        );
+        assert_eq!(
+            parse_date("Fri, 12 Feb 2016 14:08 +0200"),
+            Some(utc(2016, 2, 12, 12, 8, 0))
+        );
+        assert_eq!(
+            parse_date("12 Feb 2016 04:08 GMT"),
+            Some(utc(2016, 2, 12, 4, 8, 0))
+        );
+        assert_eq!(seconds_before_zone("Fri, 12 Feb 2016 14:08:24 +0000"), None);
+    }
+
+    #[test]
+    fn test_english_period_boundary() {
+        let parser = DateParser::new().allow_english(true);
+        assert_eq!(
+            parser.parse("end of April 2016"),
+            Some(utc(2016, 4, 30, 23, 59, 59))
+        );
+        assert_eq!(
+            parser.parse("start of April 2016"),
+            Some(utc(2016, 4, 1, 0, 0, 0))
+        );
+        assert_eq!(parse_date("end of April 2016"), None);
+        assert_eq!(parse_date("start of 2016"), None);
+        assert_eq!(parser.parse("2016-04-21"), parse_date("2016-04-21"));
+    }
+
+    #[test]
+    fn test_english_oclock() {
+        let parser = DateParser::new().allow_english(true);
+        assert_eq!(
+            parser.parse("8 o'clock April 21, 2016"),
+            Some(utc(2016, 4, 21, 8, 0, 0))
+        );
+        assert_eq!(
+            parser.parse("8 oclock pm April 21, 2016"),
+            Some(utc(2016, 4, 21, 20, 0, 0))
+        );
+        assert_eq!(parse_date("8 o'clock April 21, 2016"), None);
+    }
+
+    #[test]
+    fn test_english_day_range() {
+        let parser = DateParser::new().allow_english(true);
+        assert_eq!(
+            parser.parse("April 21\u{2013}23, 2016"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(
+            parser.parse_range(" April 21\u{2013}23, 2016 "),
+            Some((utc(2016, 4, 21, 0, 0, 0), utc(2016, 4, 23, 0, 0, 0)))
+        );
+        assert_eq!(parse_date("April 21\u{2013}23, 2016"), None);
+        assert_eq!(
+            DateParser::new().parse_range("April 21\u{2013}23, 2016"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_leniency_presets() {
+        let strict = DateParser::preset(Leniency::Strict);
+        assert_eq!(
+            strict.parse("2016-04-21T08:30:00+02:00"),
+            Some(utc(2016, 4, 21, 6, 30, 0))
+        );
+        assert_eq!(
+            strict.parse("Fri, 12 Feb 2016 14:08:24 GMT"),
+            Some(utc(2016, 2, 12, 14, 8, 24))
+        );
+        assert_eq!(strict.parse(" 2016-04-21T08:30:00Z"), None);
+        assert_eq!(strict.parse("2016-04-21T08:30:00"), None);
+        assert_eq!(strict.parse("2016-04-21"), None);
+        assert_eq!(strict.parse("@1451606400"), None);
+        assert_eq!(strict.parse("April 21, 2016"), None);
+        assert_eq!(strict.parse("2016-04-21t08:30:00z"), None);
+        assert_eq!(strict.parse("2016-04-21T08:30:00+0200"), None);
+        assert_eq!(strict.parse("Fri, 12 Feb 16 14:08:24 +0000"), None);
+        assert_eq!(strict.parse("Fri, 12 Feb 2016 14:08:24 A"), None);
+        assert_eq!(strict.parse("Fri, 12 Feb 2016 14:08:24 +0000 (UTC)"), None);
+        assert_eq!(strict.parse("fri, 12 feb 2016 14:08:24 +0000"), None);
+        assert_eq!(
+            strict.parse("12 Feb 2016 14:08 -0500"),
+            Some(utc(2016, 2, 12, 19, 8, 0))
+        );
+        assert_eq!(
+            strict.parse("Fri, 12 Feb 2016 14:08:24 PST"),
+            Some(utc(2016, 2, 12, 22, 8, 24))
+        );
+        assert_eq!(strict.parse("Fri, 30 Feb 2016 14:08:24 +0000"), None);
+
+        let strict_english = DateParser::preset(Leniency::Strict)
+            .allow_english(true)
+            .allow_missing_time_separator(true);
+        assert_eq!(strict_english.parse("end of April 2016"), None);
+        assert_eq!(strict_english.parse("2016-04-2108:30:00"), None);
+        let strict_recent = DateParser::preset(Leniency::Strict).year_range(2017, 2099);
+        assert_eq!(strict_recent.parse("2016-04-21T08:30:00Z"), None);
+
+        assert_eq!(DateParser::preset(Leniency::Default), DateParser::new());
+
+        let loose = DateParser::preset(Leniency::Loose);
+        assert_eq!(
+            loose.parse("end of April 2016"),
+            Some(utc(2016, 4, 30, 23, 59, 59))
+        );
+        assert_eq!(loose.parse("2016-04-21"), parse_date("2016-04-21"));
+    }
+
+    #[test]
+    fn test_english_day_of_month() {
+        let loose = DateParser::preset(Leniency::Loose);
+        assert_eq!(
+            loose.parse("Monday the 21st of April 2016"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(
+            loose.parse("Thursday, the 21st of April 2016"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(parse_date("Monday the 21st of April 2016"), None);
+    }
+
+    #[test]
+    fn test_basic_format_with_offset() {
+        let basic = parse_date("20160421T083000+0200").unwrap();
+        assert_eq!(Some(basic), parse_date("2016-04-21T08:30:00+02:00"));
+        assert_eq!(basic.offset().local_minus_utc(), 7200);
+        assert_eq!(
+            parse_date("20160421T083000-0530"),
+            parse_date("2016-04-21T14:00:00Z")
+        );
+        assert_eq!(parse_date("20160421T253000+0200"), None);
+    }
+
+    #[test]
+    fn test_extended_date_basic_time() {
+        assert_eq!(
+            parse_date("2016-04-21T083000Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parse_date("2016-04-21T083000+0200"),
+            parse_date("2016-04-21T08:30:00+02:00")
+        );
+    }
+
+    #[test]
+    fn test_posix_tz_rejected() {
+        let inputs = [
+            "PDT8PST",
+            "PST8PDT,M3.2.0,M11.1.0",
+            "2016-04-21 PST8PDT",
+            "2016-04-21T08:30:00 PST8PDT",
+            "2016-04-21T08:30:00Z PST8PDT",
+            "2016-04-21 08:30:00 EST5EDT,M3.2.0,M11.1.0",
+            "2016-04-21T08:30:00 CET-1CEST,M3.5.0,M10.5.0/3",
+            "2016-04-21T08PST8",
+            "Fri, 12 Feb 2016 14:08:24 PST8PDT",
+        ];
+        for input in inputs {
+            assert_eq!(parse_date(input), None, "{}", input);
+        }
+        assert_eq!(
+            parse_date("2016-04-21 08:30:00 GMT+5"),
+            parse_date("2016-04-21T08:30:00+05:00")
+        );
+        assert_eq!(
+            parse_date("2016-04-21 08:30:00 Etc/GMT-5"),
+            parse_date("2016-04-21T08:30:00+05:00")
+        );
+        assert_eq!(parse_date("APR21 2016"), Some(utc(2016, 4, 21, 0, 0, 0)));
+    }
+
+    #[test]
+    fn test_month_name_with_zone() {
+        assert_eq!(
+            parse_date("Mar 5 2016 CST"),
+            parse_date("2016-03-05T00:00:00-06:00")
+        );
+        assert_eq!(
+            parse_date("May 5, 2016 PST"),
+            parse_date("2016-05-05T00:00:00-08:00")
+        );
+        assert_eq!(
+            parse_date("5 March 2016 +01:00"),
+            parse_date("2016-03-05T00:00:00+01:00")
+        );
+        assert_eq!(parse_date("CST 5 2016"), None);
+        assert_eq!(parse_date("Mar 5 2016 Mar"), None);
+    }
+
+    #[test]
+    fn test_week_numbering() {
+        assert_eq!(parse_date("2016-W15"), Some(utc(2016, 4, 11, 0, 0, 0)));
+        assert_eq!(parse_date("2016-W53-1"), None);
+        let cdc = DateParser::new().with_week_numbering(WeekNumbering::UsCdc);
+        assert_eq!(cdc.parse("2016-W15-6"), Some(utc(2016, 4, 15, 0, 0, 0)));
+        assert_eq!(
+            cdc.parse("2016-W15-6T08:30:00+02:00"),
+            parse_date("2016-04-15T08:30:00+02:00")
+        );
+        let start = NaiveDate::from_ymd_opt(2015, 10, 1).unwrap();
+        let start = WeekNumbering::FiscalStarting(start);
+        let fiscal = DateParser::new().with_week_numbering(start);
+        assert_eq!(fiscal.parse("2016-W02"), Some(utc(2016, 10, 8, 0, 0, 0)));
+        assert_eq!(fiscal.parse("2016-04-21"), parse_date("2016-04-21"));
+    }
+
+    #[test]
+    fn test_month_name_with_time() {
+        assert_eq!(
+            parse_date("April 21, 2016 8.30 PM"),
+            Some(utc(2016, 4, 21, 20, 30, 0))
+        );
+        assert_eq!(
+            parse_date("April 21, 2016 8:30 pm"),
+            Some(utc(2016, 4, 21, 20, 30, 0))
+        );
+        assert_eq!(
+            parse_date("April 21, 2016 12.00 AM"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(
+            parse_date("April 21, 2016 12.00 PM"),
+            Some(utc(2016, 4, 21, 12, 0, 0))
+        );
+        assert_eq!(
+            parse_date("21 April 2016 08:30:15"),
+            Some(utc(2016, 4, 21, 8, 30, 15))
+        );
+        assert_eq!(
+            parse_date("Apr 21 2016 8:30 PM EST"),
+            parse_date("2016-04-21T20:30:00-05:00")
+        );
+        assert_eq!(parse_date("April 21, 2016 13.00 PM"), None);
+        assert_eq!(parse_date("April 21, 2016 8.30.15"), None);
+    }
+
+    #[test]
+    fn test_year_range() {
+        assert_eq!(
+            parse_date("0001-01-01T00:00:00Z"),
+            Some(utc(1, 1, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            parse_date("9999-12-31T23:59:59Z"),
+            Some(utc(9999, 12, 31, 23, 59, 59))
+        );
+        assert_eq!(parse_date("0000-12-31T23:59:59Z"), None);
+        assert_eq!(parse_date("@253402300800"), None);
+        assert_eq!(parse_date("-62135596801"), None);
+
+        let parser = DateParser::new().year_range(2000, 2020);
+        assert_eq!(
+            parser.parse("2020-12-31T23:59:59Z"),
+            Some(utc(2020, 12, 31, 23, 59, 59))
+        );
+        assert_eq!(parser.parse("2021-01-01T00:00:00Z"), None);
+        assert_eq!(parser.parse("1999-12-31"), None);
+        assert_eq!(parser.parse("@0"), None);
+    }
+
+    #[test]
+    fn test_twitter_year_before_offset() {
+        let swapped = parse_date("Sun Dec 24 13:19:25 2017 +0200").unwrap();
+        assert_eq!(Some(swapped), parse_date("Sun Dec 24 13:19:25 +0200 2017"));
+        assert_eq!(swapped.offset().local_minus_utc(), 7200);
+    }
+
+    #[test]
+    fn test_dotted_meridiem() {
+        assert_eq!(
+            parse_date("April 21, 2016 8:30 p.m."),
+            Some(utc(2016, 4, 21, 20, 30, 0))
+        );
+        assert_eq!(
+            parse_date("April 21, 2016 8:30 A.M."),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parse_date("April 21, 2016 12:00 a.m."),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(
+            parse_date("April 21, 2016 12.00 P.M."),
+            Some(utc(2016, 4, 21, 12, 0, 0))
+        );
+        assert_eq!(
+            DateParser::new()
+                .allow_english(true)
+                .parse("8 o'clock p.m. April 21, 2016"),
+            Some(utc(2016, 4, 21, 20, 0, 0))
+        );
+        assert_eq!(dotted_meridiem("April 21, 2016 8:30 pm"), None);
+    }
+
+    #[test]
+    fn test_is_date() {
+        let inputs = [
+            "2016-04-21T08:30:00Z",
+            "2016-04-21T08:30:00.123456+02:00",
+            "2016-02-29T23:59:59-23:59",
+            "2015-02-29T08:30:00Z",
+            "2016-04-21T24:00:00Z",
+            "2016-04-21T08:60:00Z",
+            "2016-04-21T08:30:60Z",
+            "2016-04-21T08:30:00.Z",
+            "2016-04-21T08:30:00+24:00",
+            "2016-04-21T08:30:00+02:60",
+            "2016-04-21T08:30:00+0200",
+            "0000-04-21T08:30:00Z",
+            "2016-04-21t08:30:00z",
+            "2016-04-21",
+            "April 21, 2016",
+            "Yesterday",
+            "",
+        ];
+        for input in inputs {
+            assert_eq!(is_date(input), parse_date(input).is_some(), "{}", input);
+        }
+        let parser = DateParser::new().year_range(2000, 2010);
+        assert!(!parser.is_date("2016-04-21T08:30:00Z"));
+        assert_eq!(
+            canonical_rfc3339_year("2016-04-21T08:30:00Z", 59),
+            Some(2016)
+        );
+        assert_eq!(canonical_rfc3339_year("2016-04-21 08:30:00Z", 59), None);
+    }
+
+    #[test]
+    fn test_decimal_time_components() {
+        let parser = DateParser::new().allow_decimal_time_components(true);
+        assert_eq!(
+            parser.parse("2016-04-21T08.5"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parser.parse("2016-04-21T08:30.5"),
+            Some(utc(2016, 4, 21, 8, 30, 30))
+        );
+        assert_eq!(
+            parser.parse("2016-04-21T08.25+02:00"),
+            parse_date("2016-04-21T08:15:00+02:00")
+        );
+        assert_eq!(
+            parser.parse("2016-04-21T08:30.25Z"),
+            Some(utc(2016, 4, 21, 8, 30, 15))
+        );
+        assert_eq!(
+            parser.parse("2016-04-21T08.999Z"),
+            Some(utc(2016, 4, 21, 8, 59, 56) + Duration::milliseconds(400))
+        );
+        assert_eq!(
+            parser.parse("2016-04-21T08:30:00.5Z"),
+            parse_date("2016-04-21T08:30:00.5Z")
+        );
+        assert_eq!(
+            parser.parse("2016-04-21 08.5"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parser.parse("2016-04-21T08.000000000001Z"),
+            Some(utc(2016, 4, 21, 8, 0, 0) + Duration::nanoseconds(3))
+        );
+        assert_eq!(
+            parser.parse("2016-04-21 08.5 +02:00"),
+            parse_date("2016-04-21T08:30:00+02:00")
+        );
+        assert_eq!(parser.parse("2016-04-21T24.5"), None);
+        assert_eq!(parser.parse("2016-04-21T08:60.5"), None);
+        assert!(matches!(
+            parser.try_parse("2016-04-21T24.5"),
+            Err(ParseDateError::Rejected { .. })
+        ));
+        assert_eq!(decimal_time_components("2016-04-21T08."), None);
+        assert_eq!(parse_date("2016-04-21T08.5"), None);
+        assert_eq!(parse_date("2016-04-21 08.5"), None);
+        assert_eq!(parse_date("2016-04-21T08:30.5"), None);
+    }
 
-fn cut(string: &str, len: usize) -> Option<&str> {
-    if string.len() >= len && string.is_char_boundary(len) {
-        Some(&string[..len])
-    } else {
-        None
+    #[test]
+    fn test_at_keywords() {
+        let inputs = [
+            "@reboot",
+            "@daily",
+            "@hourly",
+            "@yearly",
+            "@midnight 2016-04-21",
+            "@",
+            "@ 1451606400",
+            "@1e9",
+            "@0x10",
+        ];
+        for input in inputs {
+            assert_eq!(epoch(input), None, "{}", input);
+            assert_eq!(parse_date(input), None, "{}", input);
+            assert_eq!(
+                DateParser::preset(Leniency::Loose).parse(input),
+                None,
+                "{}",
+                input
+            );
+        }
     }
-}
 
-fn suffix(string: &str, suffix: &'static str) -> String {
-    format!("{}{}", string, suffix)
-}
+    #[test]
+    fn test_strict_rfc3339() {
+        let valid = [
+            "2016-04-21T08:30:00Z",
+            "2016-04-21T08:30:00.123456789+02:00",
+            "2016-04-21T08:30:00-00:00",
+            "2016-12-31T23:59:60Z",
+            "2016-02-29T00:00:00+23:59",
+            "0000-01-01T00:00:00Z",
+        ];
+        for input in valid {
+            assert!(is_rfc3339(input), "{}", input);
+            assert_eq!(parse_rfc3339_strict(input), rfc3339(input).ok());
+        }
+        let invalid = [
+            "2016-04-21t08:30:00Z",
+            "2016-04-21T08:30:00z",
+            "2016-04-21 08:30:00Z",
+            "2016-04-21T08:30Z",
+            "2016-04-21T08:30:00",
+            "2016-04-21T08:30:00+0200",
+            "2016-04-21T08:30:00+02",
+            "2016-04-21T08:30:00.Z",
+            " 2016-04-21T08:30:00Z",
+            "2016-04-21T08:30:00Z ",
+            "2016-4-21T08:30:00Z",
+            "2016-04-21T8:30:00Z",
+            "2016-13-21T08:30:00Z",
+            "2015-02-29T08:30:00Z",
+            "2016-04-21T24:00:00Z",
+            "2016-04-21T08:30:61Z",
+            "2016-04-21",
+        ];
+        for input in invalid {
+            assert!(!is_rfc3339(input), "{}", input);
+        }
+    }
 
-fn rfc3339<T: AsRef<str>>(string: T) -> Option<DateTime<FixedOffset>> {
-    DateTime::parse_from_rfc3339(string.as_ref()).ok()
-}
+    #[test]
+    fn test_english_spelled_out_zone() {
+        let loose = DateParser::preset(Leniency::Loose);
+        let input = "Friday, April 21, 2016 8:30:00 AM Eastern Standard Time";
+        assert_eq!(loose.parse(input), parse_date("2016-04-21T08:30:00-05:00"));
+        assert_eq!(parse_date(input), None);
+    }
 
-fn rfc2822<T: AsRef<str>>(string: T) -> Option<DateTime<FixedOffset>> {
-    DateTime::parse_from_rfc2822(string.as_ref()).ok()
-}
+    #[test]
+    fn test_parse_system_time() {
+        assert_eq!(
+            parse_system_time("2016-04-21T08:30:00.5+02:00"),
+            Some(UNIX_EPOCH + StdDuration::from_millis(1_461_220_200_500))
+        );
+        assert_eq!(
+            parse_system_time("1969-12-31T23:59:58.75Z"),
+            Some(UNIX_EPOCH - StdDuration::from_millis(1250))
+        );
+        assert_eq!(
+            parse_system_time("1900-01-01"),
+            Some(UNIX_EPOCH - StdDuration::from_secs(2_208_988_800))
+        );
+        assert_eq!(parse_system_time("Yesterday"), None);
+    }
 
-fn utc_datetime(string: &str, format: &str) -> Option<DateTime<FixedOffset>> {
-    NaiveDateTime::parse_from_str(string, format)
-        .map(|d| Utc.from_utc_datetime(&d))
-        .ok()
-        .map(|d: DateTime<Utc>| d.into())
-}
+    #[test]
+    fn test_leading_meridiem() {
+        let parser = DateParser::new().allow_leading_meridiem(true);
+        assert_eq!(
+            parser.parse("PM 8:30 April 21 2016"),
+            Some(utc(2016, 4, 21, 20, 30, 0))
+        );
+        assert_eq!(
+            parser.parse("April 21, 2016 am 12.15"),
+            Some(utc(2016, 4, 21, 0, 15, 0))
+        );
+        assert_eq!(
+            DateParser::preset(Leniency::Loose).parse("p.m. 8:30 April 21 2016"),
+            Some(utc(2016, 4, 21, 20, 30, 0))
+        );
+        assert_eq!(parse_date("PM 8:30 April 21 2016"), None);
+        assert_eq!(leading_meridiem("April 21 2016 8:30 PM"), None);
+        assert_eq!(leading_meridiem("PM April 21 2016"), None);
+    }
 
-fn utc_date(string: &str, format: &str) -> Option<DateTime<FixedOffset>> {
-    let date = NaiveDate::parse_from_str(string, format).ok()?;
-    let time = NaiveTime::from_hms_opt(0, 0, 0)?;
-    let datetime = NaiveDateTime::new(date, time);
-    Some(Utc.from_utc_datetime(&datetime).into())
-}
+    #[test]
+    fn test_expanded_year_ordinal_and_week_dates() {
+        assert_eq!(parse_date("2016-112"), Some(utc(2016, 4, 21, 0, 0, 0)));
+        assert_eq!(parse_date("+002016-112"), parse_date("2016-112"));
+        assert_eq!(parse_date("+002016-W15-5"), parse_date("2016-W15-5"));
+        assert_eq!(
+            parse_date("+002016-112T08:30:00+02:00"),
+            parse_date("2016-04-21T08:30:00+02:00")
+        );
+        assert_eq!(
+            parse_date("+2016-W15-5T08:30:00Z"),
+            parse_date("2016-04-15T08:30:00Z")
+        );
+        assert_eq!(parse_date("2015-366"), None);
+        assert_eq!(parse_date("+0002016-000"), None);
+        assert_eq!(parse_date("-000044-075"), None);
+        assert_eq!(parse_date("02016-112"), None);
+    }
 
-/// Parses a string using multiple formats
-///
-/// # Example
-///
-/// ```rust
-/// # use diligent_date_parser::parse_date;
-/// # use diligent_date_parser::chrono::prelude::*;
-/// # use diligent_date_parser::chrono::offset::FixedOffset;
-/// let datetime = parse_date("Mon, 2 Jan 2006 15:04:05 MST");
-/// let expected = FixedOffset::west(7 * 3600).ymd(2006, 1, 2).and_hms(15, 4, 5);
-/// assert_eq!(datetime, Some(expected));
-/// ```
-pub fn parse_date(string: &str) -> Option<DateTime<FixedOffset>> {
-    let trimmed = string.trim();
-    None.or_else(|| rfc3339(trimmed))
-        .or_else(|| DateTime::parse_from_str(trimmed, "%Y-%m-%d %H:%M:%S%.3f %z").ok())
-        .or_else(|| utc_datetime(trimmed, "%Y-%m-%d %H:%M:%S%.3f"))
-        .or_else(|| cut(trimmed, 20).and_then(rfc3339))
-        .or_else(|| cut(trimmed, 19).map(|s| suffix(s, "Z")).and_then(rfc3339))
-        .or_else(|| {
-            cut(trimmed, 16)
-                .map(|s| suffix(s, ":00Z"))
-                .and_then(rfc3339)
-        })
-        .or_else(|| {
-            cut(trimmed, 13)
-                .map(|s| suffix(s, ":00:00Z"))
-                .and_then(rfc3339)
-        })
-        .or_else(|| {
-            cut(trimmed, 10)
-                .map(|s| suffix(s, "T00:00:00Z"))
-                .and_then(rfc3339)
-        })
-        .or_else(|| rfc2822(trimmed))
-        .or_else(|| rfc2822(suffix(trimmed, " +0000")))
-        .or_else(|| rfc2822(suffix(trimmed, ":00 +0000")))
-        .or_else(|| rfc2822(suffix(trimmed, ":00:00 +0000")))
-        .or_else(|| rfc2822(suffix(trimmed, " 00:00:00 +0000")))
-        .or_else(|| DateTime::parse_from_str(trimmed, "%a %b %d %H:%M:%S %z %Y").ok()) // twitter's format
-        .or_else(|| utc_date(trimmed, "%b %d %Y"))
-        .or_else(|| utc_date(trimmed, "%b %e %Y"))
-        .or_else(|| utc_date(trimmed, "%B %d %Y"))
-        .or_else(|| utc_date(trimmed, "%B %e %Y"))
-        .or_else(|| utc_date(trimmed, "%b %d, %Y"))
-        .or_else(|| utc_date(trimmed, "%b %e, %Y"))
-        .or_else(|| utc_date(trimmed, "%B %d, %Y"))
-        .or_else(|| utc_date(trimmed, "%B %e, %Y"))
-        .or_else(|| utc_date(trimmed, "%m/%d/%Y"))
-        .or_else(|| utc_date(trimmed, "%d.%m.%Y"))
-}
+    #[test]
+    fn test_parse_date_parts() {
+        let instant = Utc.with_ymd_and_hms(2016, 4, 21, 14, 0, 0).unwrap();
+        assert_eq!(
+            parse_date_parts("2016-04-21T08:30:00-05:30"),
+            Some((instant, FixedOffset::west_opt(19800).unwrap()))
+        );
+        assert_eq!(
+            parse_date_parts("2016-04-21 14:00:00"),
+            Some((instant, FixedOffset::east_opt(0).unwrap()))
+        );
+        assert_eq!(parse_date_parts("Yesterday"), None);
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use chrono::Duration;
+    #[test]
+    fn test_missing_time_separator() {
+        let parser = DateParser::new().allow_missing_time_separator(true);
+        assert_eq!(
+            parser.parse("2016-04-2108:30:00"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parser.parse("2016-04-2108:30+02:00"),
+            parse_date("2016-04-21T08:30:00+02:00")
+        );
+        assert_eq!(
+            DateParser::preset(Leniency::Loose).parse("2016-04-2108:30:00Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(parse_date("2016-04-2108:30:00"), None);
+        assert_eq!(parse_date("2016-04-2108:30:00Z"), None);
+        assert_eq!(parser.parse("2016-04-21"), parse_date("2016-04-21"));
+    }
 
-    fn utc(year: i32, mon: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<FixedOffset> {
-        Utc.with_ymd_and_hms(year, mon, day, hour, min, sec)
-            .unwrap()
-            .into()
+    #[test]
+    fn test_localized() {
+        let parser = DateParser::new().allow_localized(true);
+        assert_eq!(
+            parser.parse("21. April 2016 um 08:30"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parser.parse("21 avril 2016 à 8h30"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parser.parse("21 de abril de 2016 a las 8:30"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parser.parse("1. Dezember 2016"),
+            Some(utc(2016, 12, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            parser.parse("April 21, 2016 8:30 am"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parser.parse("2016-04-21 um 08:30"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(parse_date("21. April 2016 um 08:30"), None);
     }
 
     #[test]
-    fn test_parse_date() {
+    fn test_three_digit_year() {
+        let parser = DateParser::new().allow_three_digit_year(true);
         assert_eq!(
-            parse_date("2011-11-17T08:00:00-08:00"),
-            Some(utc(2011, 11, 17, 16, 0, 0))
+            parser.parse("Fri, 12 Feb 116 14:08:24 +0000"),
+            Some(utc(2016, 2, 12, 14, 8, 24))
         );
+        assert_eq!(parser.parse("12 Feb 116"), Some(utc(2016, 2, 12, 0, 0, 0)));
+        assert_eq!(parse_date("Fri, 12 Feb 116 14:08:24 +0000"), None);
+        assert_eq!(parse_date("12 Feb 116"), None);
         assert_eq!(
-            parse_date("2011-11-17T08:00:00-08:00"),
-            Some(
-                FixedOffset::west_opt(8 * 3600)
-                    .unwrap()
-                    .with_ymd_and_hms(2011, 11, 17, 8, 0, 0)
-                    .unwrap()
-            )
+            DateParser::preset(Leniency::Strict).parse("Fri, 12 Feb 116 14:08:24 +0000"),
+            None
+        );
+        assert_eq!(
+            parse_date("Fri, 12 Feb 2016 14:08:24 +0000"),
+            Some(utc(2016, 2, 12, 14, 8, 24))
+        );
+    }
+
+    #[test]
+    fn test_date_only_time() {
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let parser = DateParser::new().date_only_time(noon);
+        let inputs = [
+            "2016-04-21",
+            "2016-04-21 +00:00",
+            "2016-112",
+            "2016-W16-4",
+            "Thu, 21 Apr 2016",
+            "April 21, 2016",
+            "21 April 2016",
+            "04/21/2016",
+            "21.04.2016",
+        ];
+        for input in inputs {
+            assert_eq!(
+                parser.parse(input),
+                Some(utc(2016, 4, 21, 12, 0, 0)),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            parser.parse("2016-04-21 +02:00"),
+            parse_date("2016-04-21T12:00:00+02:00")
+        );
+        assert_eq!(
+            parser.parse("2016-04-21T08:30:00"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
         );
         assert_eq!(
-            parse_date("2011-11-23T18:12:20Z"),
-            Some(utc(2011, 11, 23, 18, 12, 20))
+            parser.parse("2016-04-21T00:00:00Z"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
         );
         assert_eq!(
-            parse_date("2011-12-10T14:32:42+00:00"),
-            Some(utc(2011, 12, 10, 14, 32, 42))
+            parser.allow_english(true).parse("the 21st of April 2016"),
+            Some(utc(2016, 4, 21, 12, 0, 0))
         );
         assert_eq!(
-            parse_date("2010-02-17T00:00:00ZT00:00:00-08:00"),
-            Some(utc(2010, 2, 17, 0, 0, 0))
+            parser
+                .allow_english(true)
+                .parse_range("April 21\u{2013}23, 2016"),
+            Some((utc(2016, 4, 21, 12, 0, 0), utc(2016, 4, 23, 12, 0, 0)))
         );
+        assert_eq!(parse_date("2016-04-21"), Some(utc(2016, 4, 21, 0, 0, 0)));
+    }
+
+    #[test]
+    fn test_space_separated_fraction_with_zone() {
+        let expected = Some(utc(2016, 4, 21, 8, 30, 0) + Duration::milliseconds(123));
+        assert_eq!(parse_date("2016-04-21 08:30:00.123 UTC"), expected);
+        assert_eq!(parse_date("2016-04-21 08:30:00.123 Z"), expected);
+        assert_eq!(parse_date("2016-04-21 08:30:00.123 +00:00"), expected);
         assert_eq!(
-            parse_date("2010-12-21T19:57:37+00:00"),
-            Some(utc(2010, 12, 21, 19, 57, 37))
+            parse_date("2016-04-21 08:30:00.123456 UTC"),
+            Some(utc(2016, 4, 21, 8, 30, 0) + Duration::microseconds(123456))
         );
         assert_eq!(
-            parse_date("2012-02-14T17:58:00-08:00"),
-            Some(utc(2012, 2, 15, 1, 58, 0))
+            parse_date("2016-04-21 08:30:00.5+02"),
+            parse_date("2016-04-21T08:30:00.5+02:00")
         );
+    }
+
+    #[test]
+    fn test_parse_partial() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert_eq!(parse_partial(" 2016 "), Some(PartialDate::Year(2016)));
         assert_eq!(
-            parse_date("2012-02-15T12:24:00+02:00"),
-            Some(utc(2012, 2, 15, 10, 24, 0))
+            parse_partial("2016-04"),
+            Some(PartialDate::YearMonth(2016, Month::April))
         );
         assert_eq!(
-            parse_date("2013-03-20T10:46:37.600732+02:00"),
-            Some(utc(2013, 3, 20, 8, 46, 37) + Duration::microseconds(600732))
+            parse_partial("2016-04-21"),
+            Some(PartialDate::Date(date(2016, 4, 21)))
         );
         assert_eq!(
-            parse_date("2013-03-20T14:00:00.000000+02:00"),
-            Some(utc(2013, 3, 20, 12, 0, 0))
+            parse_partial("Thu, 21 Apr 2016"),
+            Some(PartialDate::Date(date(2016, 4, 21)))
         );
         assert_eq!(
-            parse_date("2013-10-21T18:23:10.394069+03:00"),
-            Some(utc(2013, 10, 21, 15, 23, 10) + Duration::microseconds(394069))
+            parse_partial("2016-04-21T08:30:00+02:00"),
+            parse_date("2016-04-21T08:30:00+02:00").map(PartialDate::DateTime)
         );
         assert_eq!(
-            parse_date("2014-01-08T01:18:21"),
-            Some(utc(2014, 1, 8, 1, 18, 21))
+            parse_partial("2016-04-21T00:00:00Z"),
+            Some(PartialDate::DateTime(utc(2016, 4, 21, 0, 0, 0)))
         );
         assert_eq!(
-            parse_date("2014-01-07T20:45"),
-            Some(utc(2014, 1, 7, 20, 45, 0))
+            DateParser::new()
+                .date_only_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap())
+                .parse_partial("2016-04-21"),
+            Some(PartialDate::Date(date(2016, 4, 21)))
         );
-        assert_eq!(parse_date("2014-01-08T13"), Some(utc(2014, 1, 8, 13, 0, 0)));
-        assert_eq!(parse_date("2014-01-11"), Some(utc(2014, 1, 11, 0, 0, 0)));
+        assert_eq!(parse_partial("0000"), None);
+        assert_eq!(parse_partial("Yesterday"), None);
+    }
 
+    #[test]
+    fn test_localized_zone_comment() {
+        // The numeric offset wins over whatever the comment names
+        let offset = |string| parse_date(string).map(|d| d.offset().local_minus_utc());
         assert_eq!(
-            parse_date("2014-01-11 01:18:21 +0000"),
-            Some(utc(2014, 01, 11, 1, 18, 21))
+            parse_date("Thu, 21 Apr 2016 08:30:00 +0100 (heure de Paris)"),
+            Some(utc(2016, 4, 21, 7, 30, 0))
         );
         assert_eq!(
-            parse_date("2014-01-11 01:18:21 +0100"),
-            Some(
-                FixedOffset::east_opt(3600)
-                    .unwrap()
-                    .with_ymd_and_hms(2014, 01, 11, 1, 18, 21)
-                    .unwrap()
-            )
+            offset("Thu, 21 Apr 2016 08:30:00 +0100 (heure de Paris)"),
+            Some(3600)
         );
         assert_eq!(
-            parse_date(" 2014-01-11 01:18:21 "),
-            Some(utc(2014, 01, 11, 1, 18, 21))
+            offset("Thu, 21 Apr 2016 08:30:00 +0100 (heure d'été d'Europe centrale)"),
+            Some(3600)
         );
+        assert_eq!(offset("Thu, 21 Apr 2016 08:30:00 +0100 (MEZ)"), Some(3600));
         assert_eq!(
-            parse_date(" 2014-01-11 01:18:21.125 "),
-            Some(utc(2014, 01, 11, 1, 18, 21) + Duration::milliseconds(125))
+            offset("Thu, 21 Apr 2016 08:30:00 -0100 (MESZ)"),
+            Some(-3600)
         );
         assert_eq!(
-            parse_date("Fri, 12 Feb 2016 14:08:24 +0000"),
+            parse_date("Thu, 21 Apr 2016 08:30:00 GMT (MEZ)"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+    }
+
+    #[test]
+    fn test_attempt_budget() {
+        let rfc2822 = "Fri, 12 Feb 2016 14:08:24 +0000";
+        let parser = |budget| DateParser::new().attempt_budget(budget);
+        assert_eq!(parser(2).parse(rfc2822), None);
+        assert_eq!(parser(2).parse_debug(rfc2822), (None, 2));
+        assert_eq!(parser(3).parse(rfc2822), Some(utc(2016, 2, 12, 14, 8, 24)));
+        assert_eq!(
+            parser(1).parse("2016-04-21T08:30:00Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(parser(0).parse("2016-04-21T08:30:00Z"), None);
+        assert!(!parser(0).is_date("2016-04-21T08:30:00Z"));
+        assert!(parser(1).is_date("2016-04-21T08:30:00Z"));
+    }
+
+    #[test]
+    fn test_end_of_day() {
+        let offset = |string| parse_date(string).map(|d| d.offset().local_minus_utc());
+        assert_eq!(
+            parse_date("2016-04-21T24:00:00+02:00"),
+            parse_date("2016-04-22T00:00:00+02:00")
+        );
+        assert_eq!(offset("2016-04-21T24:00:00+02:00"), Some(2 * 3600));
+        assert_eq!(
+            parse_date("2016-04-21T24:00:00Z"),
+            Some(utc(2016, 4, 22, 0, 0, 0))
+        );
+        assert_eq!(
+            parse_date("2016-12-31T24:00Z"),
+            Some(utc(2017, 1, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            parse_date("2016-02-28 24:00:00.000 -05:00"),
+            Some(utc(2016, 2, 29, 5, 0, 0))
+        );
+        assert_eq!(
+            parse_date("2016-04-30T24:00:00"),
+            Some(utc(2016, 5, 1, 0, 0, 0))
+        );
+        assert!(is_date("2016-04-21T24:00:00+02:00"));
+        assert_eq!(end_of_day("2016-04-21T24:00:01Z"), None);
+        assert_eq!(end_of_day("2016-04-21T24:00:00.5Z"), None);
+        assert_eq!(end_of_day("2016-04-21T24:30:00Z"), None);
+    }
+
+    #[test]
+    fn test_labeled_date() {
+        assert_eq!(
+            parse_labeled_date("Date: 2016-04-21T08:30:00Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parse_labeled_date("Timestamp=1451606400"),
+            Some(utc(2016, 1, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            parse_labeled_date("unix_time=1451606400.5"),
+            Some(Utc.timestamp_opt(1451606400, 500_000_000).unwrap().into())
+        );
+        assert_eq!(
+            parse_labeled_date("Date: Thu, 21 Apr 2016 08:30:00 +0000"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parse_labeled_date("2016-04-21T08:30:00Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parse_labeled_date("08:30 April 21, 2016"),
+            parse_date("08:30 April 21, 2016")
+        );
+        assert_eq!(
+            parse_labeled_date("date=20160421"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(
+            parse_labeled_date("epoch=20160421"),
+            Some(utc(1970, 8, 22, 8, 7, 1))
+        );
+        assert_eq!(parse_labeled_date("date=20161321"), None);
+        assert_eq!(parse_labeled_date("x:1"), None);
+        assert_eq!(parse_labeled_date("created_at=1451606400"), None);
+        assert_eq!(
+            parse_labeled_date("_timestamp=1451606400"),
+            Some(utc(2016, 1, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            parse_labeled_date("2nd_date: 2016-04-21"),
+            Some(utc(2016, 4, 21, 0, 0, 0))
+        );
+        assert_eq!(parse_labeled_date("Date:"), None);
+        assert_eq!(parse_labeled_date("1451606400"), None);
+        assert_eq!(strip_label("a-b: 2016"), None);
+    }
+
+    #[test]
+    fn test_week_and_weekday_name() {
+        let loose = DateParser::preset(Leniency::Loose);
+        assert_eq!(loose.parse("2016 W15 Friday"), parse_date("2016-W15-5"));
+        assert_eq!(
+            loose.parse("2016 W15 Friday"),
+            Some(utc(2016, 4, 15, 0, 0, 0))
+        );
+        assert_eq!(parse_date("2016 W15 Friday"), None);
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        for input in [
+            "2016-04-21T08:30:00Z",
+            "2016-04-21T08:30:00+00:00",
+            "2016-04-21 08:30:00 UTC",
+            "Thu, 21 Apr 2016 08:30:00 GMT",
+            "Thu Apr 21 08:30:00 +0000 2016",
+            "April 21, 2016 08:30",
+            "@1461227400",
+        ] {
+            assert_eq!(
+                canonicalize(input).as_deref(),
+                Some("2016-04-21T08:30:00+00:00"),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            canonicalize("2016-04-21").as_deref(),
+            Some("2016-04-21T00:00:00+00:00")
+        );
+        assert_eq!(
+            canonicalize("2016-04-21T08:30:00.5-05:00").as_deref(),
+            Some("2016-04-21T08:30:00.500-05:00")
+        );
+        assert_eq!(canonicalize(""), None);
+    }
+
+    #[test]
+    fn test_multibyte_zone_offset() {
+        // Used to panic splitting the offset inside `à`
+        // and the cut ISO 8601 fallback still reads the date and time as UTC
+        let datetime = parse_date("2016-04-21 08:30:00 GMT+5àZ");
+        assert_eq!(datetime, Some(utc(2016, 4, 21, 8, 30, 0)));
+        assert_eq!(datetime.map(|d| *d.offset()), FixedOffset::east_opt(0));
+    }
+
+    #[test]
+    fn test_localized_keeps_english_dates() {
+        let localized = DateParser::new().allow_localized(true);
+        for input in [
+            "Fri, 12 Feb 2016 14:08:24 +0000",
+            "Tue, 12 Jan 2016 14:08:24 +0000",
+            "Fri, 12 Feb 2016 14:08:24 GMT",
+            "12 Feb 2016",
+            "Mar 5 2016 8:30 am",
+        ] {
+            assert_eq!(localized.parse(input), parse_date(input), "{}", input);
+            assert!(localized.parse(input).is_some(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_offset_token_matches_datetime() {
+        let token = |string| parse_date_components(string).unwrap().offset_token();
+        assert_eq!(token("2016-04-21T08:30:00+02:00 extra"), None);
+        assert_eq!(token("2010-02-17T00:00:00ZT00:00:00-08:00"), None);
+        assert_eq!(
+            token("Fri, 12 Feb 2016 14:08:24 +0100 (MEZ)"),
+            Some("+0100")
+        );
+        assert_eq!(token("20160421T083000+0200"), Some("+0200"));
+        assert_eq!(token("2016-04-21T083000+0200"), Some("+0200"));
+    }
+
+    #[test]
+    fn test_english_phrases_use_parser_options() {
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let parser = DateParser::new().allow_english(true).date_only_time(noon);
+        assert_eq!(
+            parser.parse("April 21, 2016 Eastern Standard Time"),
+            Some(utc(2016, 4, 21, 17, 0, 0))
+        );
+        assert_eq!(
+            parser.parse("8 o'clock April 21, 2016"),
+            Some(utc(2016, 4, 21, 8, 0, 0))
+        );
+        let recent = DateParser::new().allow_english(true).year_range(2000, 2099);
+        assert_eq!(
+            recent.parse("April 21, 1816 8:30:00 AM Eastern Standard Time"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_iana_zone_after_attached_offset() {
+        assert_eq!(
+            parse_date("2016-04-21T10:00:00-05:00 America/Chicago"),
+            Some(utc(2016, 4, 21, 15, 0, 0))
+        );
+        assert_eq!(
+            parse_date("2016-04-21T10:00:00+0530 Asia/Kolkata"),
+            Some(utc(2016, 4, 21, 4, 30, 0))
+        );
+        assert_eq!(
+            parse_date_components("2016-04-21T10:00:00-05:00 America/Chicago")
+                .unwrap()
+                .offset_token(),
+            Some("-05:00")
+        );
+    }
+
+    #[test]
+    fn test_posix_tz_in_comment() {
+        assert_eq!(
+            parse_date("Fri, 12 Feb 2016 14:08:24 +0000 (EST5EDT)"),
             Some(utc(2016, 2, 12, 14, 8, 24))
         );
+        assert!(has_posix_tz("Fri, 12 Feb 2016 14:08:24 EST5EDT (comment)"));
+        assert!(!has_posix_tz("Fri, 12 Feb 2016 14:08:24 +0000 (CET-1CEST)"));
+    }
+
+    #[test]
+    fn test_month_name_zone_needs_day() {
+        assert_eq!(parse_date("May 2016 PST"), None);
+        assert_eq!(parse_date("May 2016 +0200"), None);
+        assert_eq!(parse_partial("May 2016 PST"), None);
         assert_eq!(
-            parse_date("Fri, 13 Aug 2010 00:49:00 +0700"),
-            Some(utc(2010, 8, 12, 17, 49, 0))
+            parse_date("May 20, 2016 PST"),
+            Some(utc(2016, 5, 20, 8, 0, 0))
         );
+    }
+
+    #[test]
+    fn test_letter_leading_skips_numeric_formats() {
+        let inputs = [
+            "Fri, 12 Feb 2016 14:08:24 +0000",
+            "Fri, 14 Nov 2014 17:16:12 PST",
+            "Tue, 3 Jul 2012",
+            "Sun Dec 24 13:19:25 +0200 2017",
+            "Apr 21 2016",
+            "April 01, 2016",
+            "Z2016-04-21T08:30:00Z",
+            "T2016-04-21",
+        ];
+        for input in inputs {
+            let mut attempts = Attempts::default();
+            assert_eq!(iso8601(input, &mut attempts), None, "{}", input);
+            assert_eq!(numeric(input, &mut attempts), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_week_date() {
+        assert_eq!(parse_date("2016-W15-5"), Some(utc(2016, 4, 15, 0, 0, 0)));
+        assert_eq!(parse_date("2015-W53-5"), Some(utc(2016, 1, 1, 0, 0, 0)));
+        assert_eq!(parse_date("2016-W01-1"), Some(utc(2016, 1, 4, 0, 0, 0)));
         assert_eq!(
-            parse_date("Fri, 13 Jul 2012 07:13:31 -0600"),
-            Some(utc(2012, 7, 13, 13, 13, 31))
+            parse_date("2016-W15-5T08:30"),
+            Some(utc(2016, 4, 15, 8, 30, 0))
         );
         assert_eq!(
-            parse_date("Fri, 14 Dec 2012 04:00:00 -0800"),
-            Some(utc(2012, 12, 14, 12, 0, 0))
+            parse_date("2016-W15-5T08:30:00Z"),
+            Some(utc(2016, 4, 15, 8, 30, 0))
         );
         assert_eq!(
-            parse_date("Fri, 14 Jun 2013 05:00:00 -0700"),
-            Some(utc(2013, 6, 14, 12, 0, 0))
+            parse_date("2016-W15-5T08:30:00.250"),
+            Some(utc(2016, 4, 15, 8, 30, 0) + Duration::milliseconds(250))
         );
+        assert_eq!(parse_date("2016-W53-5"), None);
+        assert_eq!(parse_date("2016-W15-8"), None);
+    }
+
+    #[test]
+    fn test_negative_epoch() {
+        assert_eq!(parse_date("@-86400"), Some(utc(1969, 12, 31, 0, 0, 0)));
+        assert_eq!(parse_date("-86400"), Some(utc(1969, 12, 31, 0, 0, 0)));
         assert_eq!(
-            parse_date("Fri, 14 Nov 2014 17:16:12 PST"),
-            Some(utc(2014, 11, 15, 1, 16, 12))
+            parse_date("-1.25"),
+            Some(utc(1969, 12, 31, 23, 59, 58) + Duration::milliseconds(750))
         );
+        assert_eq!(parse_date("@-0"), Some(utc(1970, 1, 1, 0, 0, 0)));
+        assert_eq!(parse_date("-"), None);
+        assert_eq!(parse_date("--86400"), None);
+        assert_eq!(parse_date("@--86400"), None);
+        assert_eq!(parse_date("86400"), None);
+    }
+
+    #[test]
+    fn test_iana_zone_after_offset() {
         assert_eq!(
-            parse_date("Fri, 14 Oct 2011 04:01:47 +0000"),
-            Some(utc(2011, 10, 14, 4, 1, 47))
+            parse_date("Fri, 12 Feb 2016 14:08:24 +0000 America/New_York"),
+            Some(utc(2016, 2, 12, 14, 8, 24))
         );
         assert_eq!(
-            parse_date("Fri, 15 Apr 2016 00:00:00 +0200"),
-            Some(utc(2016, 4, 14, 22, 0, 0))
+            parse_date("Fri, 12 Feb 2016 14:08:24 +0100 America/Argentina/Buenos_Aires"),
+            Some(utc(2016, 2, 12, 13, 8, 24))
         );
         assert_eq!(
-            parse_date("Fri, 15 Apr 2016 23:02:22 GMT"),
-            Some(utc(2016, 4, 15, 23, 2, 22))
+            parse_date("2016-04-21 08:30:00 +02:00 Europe/Berlin"),
+            Some(utc(2016, 4, 21, 6, 30, 0))
         );
         assert_eq!(
-            parse_date("Fri, 15 Mar 2013 07:27:18 +0000"),
-            Some(utc(2013, 3, 15, 7, 27, 18))
+            iana_zone_after_offset("Fri, 12 Feb 2016 14:08:24 America/New_York"),
+            None
         );
         assert_eq!(
-            parse_date("Fri, 16 May 2014 02:13:00 PDT"),
-            Some(utc(2014, 5, 16, 9, 13, 0))
+            iana_zone_after_offset("Fri, 12 Feb 2016 14:08:24 +0000 New York"),
+            None
         );
+        assert_eq!(iana_zone_after_offset("2016-04-21 +0000 america/"), None);
+    }
+
+    #[test]
+    fn test_parse_date_debug() {
         assert_eq!(
-            parse_date("Tue, 3 Jul 2012 23:02:36 +0400"),
-            Some(utc(2012, 7, 3, 19, 2, 36))
+            parse_date_debug("2016-04-21T08:30:00Z"),
+            (Some(utc(2016, 4, 21, 8, 30, 0)), 1)
         );
         assert_eq!(
-            parse_date("Tue,  3  Jul 2012 23:02:36 +0400"),
-            Some(utc(2012, 7, 3, 19, 2, 36))
+            parse_date_debug(" 2016-04-21T08:30:00Z "),
+            (Some(utc(2016, 4, 21, 8, 30, 0)), 3)
         );
         assert_eq!(
-            parse_date("Tue, 3 Jul 2012 23:02:36"),
-            Some(utc(2012, 7, 3, 23, 2, 36))
+            parse_date_debug("@1451606400"),
+            (Some(utc(2016, 1, 1, 0, 0, 0)), 2)
         );
         assert_eq!(
-            parse_date("Tue, 3 Jul 2012 23:02"),
-            Some(utc(2012, 7, 3, 23, 2, 0))
+            parse_date_debug("Fri, 12 Feb 2016 14:08:24 +0000"),
+            (Some(utc(2016, 2, 12, 14, 8, 24)), 3)
         );
         assert_eq!(
-            parse_date("Tue, 3 Jul 2012 23"),
-            Some(utc(2012, 7, 3, 23, 0, 0))
+            parse_date_debug("April 21, 2016"),
+            (Some(utc(2016, 4, 21, 0, 0, 0)), 5)
         );
+        assert_eq!(parse_date_debug("Yesterday"), (None, 5));
+        assert_eq!(parse_date_debug("21/04/2016"), (None, 7));
         assert_eq!(
-            parse_date("Tue, 3 Jul 2012"),
-            Some(utc(2012, 7, 3, 0, 0, 0))
+            DateParser::new()
+                .allow_english(true)
+                .parse_debug("Yesterday"),
+            (None, 6)
         );
+    }
+
+    #[test]
+    fn test_parse_time() {
+        let time = |hour, min, sec| NaiveTime::from_hms_opt(hour, min, sec);
+        assert_eq!(parse_time("08:30:00"), time(8, 30, 0));
+        assert_eq!(parse_time(" 8:30:00 "), time(8, 30, 0));
+        assert_eq!(parse_time("08:30"), time(8, 30, 0));
         assert_eq!(
-            parse_date("3 Jul 2012 23:02:36"),
-            Some(utc(2012, 7, 3, 23, 2, 36))
+            parse_time("08:30:00.250"),
+            NaiveTime::from_hms_milli_opt(8, 30, 0, 250)
         );
+        assert_eq!(parse_time("083000"), time(8, 30, 0));
+        assert_eq!(parse_time("235959"), time(23, 59, 59));
+        assert_eq!(parse_time("243000"), None);
+        assert_eq!(parse_time("83000"), None);
+        assert_eq!(parse_time("0830000"), None);
+        assert_eq!(parse_time("08:30:61"), None);
+        assert_eq!(parse_time("2016-04-21"), None);
+        assert_eq!(parse_date("083000"), None);
+    }
 
-        assert_eq!(parse_date("14 Apr 2016"), Some(utc(2016, 4, 14, 0, 0, 0)));
-        assert_eq!(parse_date("21 Apr 2016"), Some(utc(2016, 4, 21, 0, 0, 0)));
-        assert_eq!(parse_date("28 Apr 2016"), Some(utc(2016, 4, 28, 0, 0, 0)));
-        assert_eq!(parse_date(" 7 Apr 2016"), Some(utc(2016, 4, 7, 0, 0, 0)));
+    #[test]
+    fn test_space_separated_utc() {
+        assert_eq!(
+            parse_date("2016-04-21 08:30:00Z"),
+            parse_date("2016-04-21T08:30:00Z")
+        );
+        assert_eq!(
+            parse_date("2016-04-21 08:30:00Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+        assert_eq!(
+            parse_date("2016-04-21 08:30:00.5Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0) + Duration::milliseconds(500))
+        );
+        assert_eq!(
+            parse_date("2016-04-21 08:30Z"),
+            Some(utc(2016, 4, 21, 8, 30, 0))
+        );
+    }
 
-        assert_eq!(parse_date("Apr 21 2016"), Some(utc(2016, 4, 21, 0, 0, 0)));
-        assert_eq!(parse_date(" Apr  1, 2016"), Some(utc(2016, 4, 1, 0, 0, 0)));
+    #[test]
+    fn test_gmt_zone_names() {
+        let offset = |string| parse_date(string).map(|d| d.offset().local_minus_utc());
         assert_eq!(
-            parse_date("  April 01, 2016"),
-            Some(utc(2016, 4, 1, 0, 0, 0))
+            parse_date("2016-04-21 08:30:00 GMT+5"),
+            Some(utc(2016, 4, 21, 3, 30, 0))
+        );
+        assert_eq!(
+            parse_date("2016-04-21 08:30:00 Etc/GMT+5"),
+            Some(utc(2016, 4, 21, 13, 30, 0))
+        );
+        assert_eq!(offset("2016-04-21 08:30:00 GMT+5"), Some(5 * 3600));
+        assert_eq!(offset("2016-04-21 08:30:00 Etc/GMT+5"), Some(-5 * 3600));
+        assert_eq!(offset("Fri, 12 Feb 2016 14:08:24 UTC-03:30"), Some(-12600));
+        assert_eq!(offset("Fri, 12 Feb 2016 14:08:24 Etc/GMT-2"), Some(7200));
+        assert_eq!(
+            offset("Fri, 12 Feb 2016 14:08:24 +0100 Etc/GMT+5"),
+            Some(3600)
         );
+    }
 
-        // twitter
+    #[test]
+    fn test_glued_month() {
+        assert_eq!(parse_date("Apr21 2016"), Some(utc(2016, 4, 21, 0, 0, 0)));
+        assert_eq!(parse_date("April21, 2016"), Some(utc(2016, 4, 21, 0, 0, 0)));
+        assert_eq!(parse_date("3Jul2012"), Some(utc(2012, 7, 3, 0, 0, 0)));
+        assert_eq!(parse_date("21April2016"), Some(utc(2016, 4, 21, 0, 0, 0)));
+        assert_eq!(glued_month("2016-04-21T08:30:00Z"), None);
+        assert_eq!(glued_month("2016-W15-5"), None);
+        assert_eq!(glued_month("Fri, 12 Feb 2016 14:08:24 +0000"), None);
+        assert_eq!(glued_month("Apr21 2016").as_deref(), Some("Apr 21 2016"));
+        assert_eq!(glued_month("3Jul2012").as_deref(), Some("3 Jul 2012"));
+    }
+
+    #[test]
+    fn test_unicode_minus_offset() {
         assert_eq!(
-            parse_date("Sun Dec 24 13:19:25 +0000 2017"),
-            Some(utc(2017, 12, 24, 13, 19, 25))
+            parse_date("2016-04-21T08:30:00\u{2212}02:00"),
+            Some(utc(2016, 4, 21, 10, 30, 0))
         );
         assert_eq!(
-            parse_date("Sun Dec 24 13:19:25 -0000 2017"),
-            Some(utc(2017, 12, 24, 13, 19, 25))
+            parse_date("2016-04-21T08:30:00\u{2212}02:00").map(|d| d.offset().local_minus_utc()),
+            Some(-2 * 3600)
         );
         assert_eq!(
-            parse_date("Sun Dec 24 13:19:25 +0200 2017"),
-            Some(utc(2017, 12, 24, 11, 19, 25))
+            parse_date("Fri, 12 Feb 2016 14:08:24 \u{2212}0200"),
+            Some(utc(2016, 2, 12, 16, 8, 24))
         );
         assert_eq!(
-            parse_date("Sun Dec 24 13:19:25 -0200 2017"),
-            Some(utc(2017, 12, 24, 15, 19, 25))
+            parse_date("2016-04-21 \u{2212}02:00"),
+            Some(utc(2016, 4, 21, 2, 0, 0))
         );
+        assert_eq!(unicode_minus_offset("2016\u{2212}04\u{2212}21"), None);
+        assert_eq!(unicode_minus_offset("08:30:00\u{2212}2:00"), None);
+        assert_eq!(unicode_minus_offset("2016-04-21T08:30:00-02:00"), None);
     }
 }
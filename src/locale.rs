@@ -0,0 +1,168 @@
+/// Month names in German, French and Spanish, lower case and with the
+/// number of the month. Abbreviations are matched without their period.
+const MONTHS: [(&str, u32); 50] = [
+    // German
+    ("januar", 1),
+    ("jänner", 1),
+    ("februar", 2),
+    ("märz", 3),
+    ("maerz", 3),
+    ("mai", 5),
+    ("juni", 6),
+    ("juli", 7),
+    ("oktober", 10),
+    ("dezember", 12),
+    ("jan", 1),
+    ("feb", 2),
+    ("mär", 3),
+    ("okt", 10),
+    ("dez", 12),
+    // French
+    ("janvier", 1),
+    ("février", 2),
+    ("fevrier", 2),
+    ("mars", 3),
+    ("avril", 4),
+    ("juin", 6),
+    ("juillet", 7),
+    ("août", 8),
+    ("aout", 8),
+    ("septembre", 9),
+    ("octobre", 10),
+    ("novembre", 11),
+    ("décembre", 12),
+    ("decembre", 12),
+    ("janv", 1),
+    ("févr", 2),
+    ("avr", 4),
+    ("juil", 7),
+    ("sept", 9),
+    ("déc", 12),
+    // Spanish
+    ("enero", 1),
+    ("febrero", 2),
+    ("marzo", 3),
+    ("abril", 4),
+    ("mayo", 5),
+    ("junio", 6),
+    ("julio", 7),
+    ("agosto", 8),
+    ("septiembre", 9),
+    ("setiembre", 9),
+    ("octubre", 10),
+    ("noviembre", 11),
+    ("diciembre", 12),
+    ("ene", 1),
+    ("dic", 12),
+];
+
+/// The English month names the translation produces. Names spelled the same
+/// in English, like German `April` or `August`, are not in [`MONTHS`].
+const ENGLISH_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Words that only connect the parts of a date: German `um` ("at") and
+/// `Uhr` ("o'clock"), French `le` and `à`, Spanish `el`, `de` and `a las`.
+fn is_filler(word: &str) -> bool {
+    matches!(
+        word,
+        "um" | "uhr" | "le" | "à" | "el" | "de" | "del" | "las" | "la"
+    )
+}
+
+/// Rewrites a German, French or Spanish date in English, e.g.
+/// `21. April 2016 um 08:30` as `21 April 2016 08:30`,
+/// `21 avril 2016 à 8h30` as `21 April 2016 8:30` and
+/// `21 de abril de 2016 a las 08:30` as `21 April 2016 08:30`. Returns
+/// `None` when there is nothing to rewrite.
+pub(crate) fn translate(string: &str) -> Option<String> {
+    let original: Vec<&str> = string.split_whitespace().collect();
+    let lower: Vec<String> = original.iter().map(|word| word.to_lowercase()).collect();
+    let mut translated = Vec::with_capacity(lower.len());
+    let mut changed = false;
+    for (i, word) in lower.iter().enumerate() {
+        let next = lower.get(i + 1).map(String::as_str);
+        let word = word.as_str();
+        if is_filler(word) || (word == "a" && matches!(next, Some("las" | "la"))) {
+            changed = true;
+            continue;
+        }
+        // German `am 21. April`, but not the meridiem of `8:30 am`
+        if i == 0 && word == "am" {
+            changed = true;
+            continue;
+        }
+        let stripped = word.trim_end_matches(['.', ',']);
+        if let Some(&(_, month)) = MONTHS.iter().find(|(name, _)| *name == stripped) {
+            translated.push(ENGLISH_MONTHS[month as usize - 1].to_string());
+            changed = true;
+        } else if let Some(day) = word
+            .strip_suffix('.')
+            .or_else(|| word.strip_suffix("er"))
+            .filter(|day| !day.is_empty() && day.bytes().all(|b| b.is_ascii_digit()))
+        {
+            translated.push(day.to_string());
+            changed = true;
+        } else if let Some((hour, minute)) = word.split_once('h').filter(|(hour, minute)| {
+            !hour.is_empty()
+                && hour
+                    .bytes()
+                    .chain(minute.bytes())
+                    .all(|b| b.is_ascii_digit())
+        }) {
+            let minute = if minute.is_empty() { "00" } else { minute };
+            translated.push(format!("{}:{}", hour, minute));
+            changed = true;
+        } else {
+            translated.push(original[i].to_string());
+        }
+    }
+    changed.then(|| translated.join(" "))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(
+            translate("21. April 2016 um 08:30").as_deref(),
+            Some("21 April 2016 08:30")
+        );
+        assert_eq!(
+            translate("am 1. März 2016 um 8:30 Uhr").as_deref(),
+            Some("1 March 2016 8:30")
+        );
+        assert_eq!(
+            translate("le 1er mai 2016 à 8h30").as_deref(),
+            Some("1 May 2016 8:30")
+        );
+        assert_eq!(
+            translate("21 avril 2016 à 20h").as_deref(),
+            Some("21 April 2016 20:00")
+        );
+        assert_eq!(
+            translate("21 de abril de 2016 a las 08:30").as_deref(),
+            Some("21 April 2016 08:30")
+        );
+        assert_eq!(
+            translate("21 Dez. 2016").as_deref(),
+            Some("21 December 2016")
+        );
+        assert_eq!(translate("April 21, 2016 8:30 am"), None);
+        assert_eq!(translate("2016-04-21T08:30:00Z"), None);
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{
+    at_time, canonical_rfc3339_year, compact_date, components, english, is_digits, is_epoch_label,
+    parse, strip_label, try_parse, Attempts, DateComponents, ParseDateError, PartialDate,
+    WeekNumbering,
+};
+use chrono::{offset::FixedOffset, DateTime, Datelike, NaiveTime};
+
+/// A date parser with configurable leniency.
+///
+/// [`parse_date`](crate::parse_date) is a shorthand for
+/// `DateParser::new().parse(..)`; the options below enable handling of
+/// inputs it does not accept by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use diligent_date_parser::DateParser;
+/// let parser = DateParser::new().allow_english(true);
+/// assert!(parser.parse("end of April 2016").is_some());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateParser {
+    pub(crate) english: bool,
+    pub(crate) strict: bool,
+    pub(crate) weeks: WeekNumbering,
+    pub(crate) years: (i32, i32),
+    pub(crate) decimal_time: bool,
+    pub(crate) leading_meridiem: bool,
+    pub(crate) missing_time_separator: bool,
+    pub(crate) localized: bool,
+    pub(crate) three_digit_year: bool,
+    pub(crate) date_only_time: NaiveTime,
+    pub(crate) budget: Option<usize>,
+}
+
+impl Default for DateParser {
+    fn default() -> Self {
+        Self {
+            english: false,
+            strict: false,
+            weeks: WeekNumbering::Iso,
+            years: (1, 9999),
+            decimal_time: false,
+            leading_meridiem: false,
+            missing_time_separator: false,
+            localized: false,
+            three_digit_year: false,
+            date_only_time: NaiveTime::MIN,
+            budget: None,
+        }
+    }
+}
+
+/// Bundles of options for [`DateParser::preset`]. More may be added.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Leniency {
+    /// Only exact RFC3339, as [`parse_rfc3339_strict`](crate::parse_rfc3339_strict)
+    /// accepts it, and RFC2822 in its current syntax, without trimming or
+    /// any other normalization. Obsolete RFC2822 forms like two digit years
+    /// and military zones are rejected. Both require a zone, so nothing is
+    /// assumed to be UTC.
+    Strict,
+    /// All formats [`parse_date`](crate::parse_date) accepts: ISO 8601
+    /// variants, RFC2822 variants, month names, numeric dates and epochs,
+    /// with the normalization it applies to them.
+    #[default]
+    Default,
+    /// Everything `Default` accepts, plus English phrases as enabled by
+    /// [`DateParser::allow_english`], a meridiem before the time as enabled
+    /// by [`DateParser::allow_leading_meridiem`] and a date glued to its
+    /// time as enabled by [`DateParser::allow_missing_time_separator`].
+    Loose,
+}
+
+impl DateParser {
+    /// Creates a parser that behaves like [`parse_date`](crate::parse_date).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a parser with the options of a [`Leniency`] preset. Any of
+    /// them can still be changed afterwards, except for `Strict`: it only
+    /// ever accepts its two formats, so options enabling other inputs, like
+    /// [`allow_english`](Self::allow_english), have no effect on it, while
+    /// [`year_range`](Self::year_range) and
+    /// [`attempt_budget`](Self::attempt_budget) still apply.
+    ///
+    /// ```rust
+    /// # use diligent_date_parser::{DateParser, Leniency};
+    /// let strict = DateParser::preset(Leniency::Strict);
+    /// assert!(strict.parse("2016-04-21T08:30:00Z").is_some());
+    /// assert!(strict.parse("2016-04-21").is_none());
+    /// ```
+    pub fn preset(leniency: Leniency) -> Self {
+        match leniency {
+            Leniency::Strict => Self {
+                strict: true,
+                ..Self::default()
+            },
+            Leniency::Default => Self::default(),
+            Leniency::Loose => Self::default()
+                .allow_english(true)
+                .allow_leading_meridiem(true)
+                .allow_missing_time_separator(true),
+        }
+    }
+
+    /// Enables English phrases such as `start of 2016` or
+    /// `end of April 2016`, and week dates with a weekday name like
+    /// `2016 W15 Friday`. Off by default.
+    pub fn allow_english(mut self, allow: bool) -> Self {
+        self.english = allow;
+        self
+    }
+
+    /// Enables RFC2822 dates with a three digit year, which is read as years
+    /// since 1900 (`Fri, 12 Feb 116 14:08:24 +0000` is in 2016). Old mail
+    /// clients wrote these by mistaking `getYear()` for the year. Off by
+    /// default, when such dates are rejected.
+    pub fn allow_three_digit_year(mut self, allow: bool) -> Self {
+        self.three_digit_year = allow;
+        self
+    }
+
+    /// Enables German, French and Spanish month names and the words
+    /// connecting a date to its time, as in `21. April 2016 um 08:30`,
+    /// `21 avril 2016 à 8h30` or `21 de abril de 2016 a las 08:30`. Off by
+    /// default and not part of any [`Leniency`] preset.
+    pub fn allow_localized(mut self, allow: bool) -> Self {
+        self.localized = allow;
+        self
+    }
+
+    /// Enables an `AM`/`PM` written before the time it applies to, as in
+    /// `PM 8:30 April 21 2016`, which is seen in OCR output. Off by default.
+    pub fn allow_leading_meridiem(mut self, allow: bool) -> Self {
+        self.leading_meridiem = allow;
+        self
+    }
+
+    /// Enables ISO 8601 dates glued to their time without the `T`, as in
+    /// `2016-04-2108:30:00`. Off by default, when such inputs are rejected.
+    pub fn allow_missing_time_separator(mut self, allow: bool) -> Self {
+        self.missing_time_separator = allow;
+        self
+    }
+
+    /// Enables ISO 8601 decimal fractions of the hour or minute, so that
+    /// `2016-04-21T08.5` is 08:30 and `2016-04-21T08:30.5` is 08:30:30.
+    /// Off by default, when such inputs are rejected, as they are far more
+    /// often misformatted times than deliberate fractions.
+    pub fn allow_decimal_time_components(mut self, allow: bool) -> Self {
+        self.decimal_time = allow;
+        self
+    }
+
+    /// Sets the time of day given to inputs that only have a date, such as
+    /// `2016-04-21` or `April 21, 2016`. Midnight by default; noon keeps the
+    /// date the same when the result is later converted to another zone.
+    pub fn date_only_time(mut self, time: NaiveTime) -> Self {
+        self.date_only_time = time;
+        self
+    }
+
+    /// Limits how many format families (ISO 8601, RFC2822, month names,
+    /// ...) are attempted before giving up, which bounds the time spent on
+    /// inputs that match none of them. Families are attempted in the order
+    /// [`parse_date_debug`](crate::parse_date_debug) counts them, with the
+    /// common formats first: canonical RFC3339 takes one attempt and RFC2822
+    /// three. A budget too small for an input makes it return `None` even
+    /// though it is valid. Unlimited by default.
+    pub fn attempt_budget(mut self, families: usize) -> Self {
+        self.budget = Some(families);
+        self
+    }
+
+    /// Sets how the weeks of week dates like `2016-W15-5` are numbered.
+    /// [`WeekNumbering::Iso`] by default.
+    pub fn with_week_numbering(mut self, numbering: WeekNumbering) -> Self {
+        self.weeks = numbering;
+        self
+    }
+
+    /// Limits the years of parsed dates to `min..=max`; dates outside of it
+    /// are rejected. `1..=9999` by default, which keeps a mangled epoch or
+    /// an expanded year from turning into a date nobody meant.
+    pub fn year_range(mut self, min: i32, max: i32) -> Self {
+        self.years = (min, max);
+        self
+    }
+
+    pub(crate) fn in_year_range(&self, datetime: &DateTime<FixedOffset>) -> bool {
+        (self.years.0..=self.years.1).contains(&datetime.year())
+    }
+
+    /// Parses a string using multiple formats.
+    pub fn parse(&self, string: &str) -> Option<DateTime<FixedOffset>> {
+        parse(self, string, &mut Attempts::default())
+    }
+
+    /// Tells whether a string is a date, like [`is_date`](crate::is_date)
+    /// does.
+    pub fn is_date(&self, string: &str) -> bool {
+        match canonical_rfc3339_year(string, 59).filter(|_| self.budget != Some(0)) {
+            Some(year) => (self.years.0..=self.years.1).contains(&year),
+            None => self.parse(string).is_some(),
+        }
+    }
+
+    /// Parses a range of days within a month, like `April 21–23, 2016`,
+    /// into its first and last day. [`parse`](Self::parse) returns just the
+    /// first day of such a range.
+    ///
+    /// Requires [`allow_english`](Self::allow_english).
+    pub fn parse_range(
+        &self,
+        string: &str,
+    ) -> Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+        if !self.english {
+            return None;
+        }
+        let (first, last) = english::day_range(string.trim())?;
+        let (first, last) = (
+            at_time(first, self.date_only_time),
+            at_time(last, self.date_only_time),
+        );
+        (self.in_year_range(&first) && self.in_year_range(&last)).then_some((first, last))
+    }
+
+    /// Parses a string and writes it back as RFC3339, like
+    /// [`canonicalize`](crate::canonicalize) does.
+    pub fn canonicalize(&self, string: &str) -> Option<String> {
+        self.parse(string).map(|datetime| datetime.to_rfc3339())
+    }
+
+    /// Parses a date preceded by a label, like
+    /// [`parse_labeled_date`](crate::parse_labeled_date) does.
+    pub fn parse_labeled(&self, string: &str) -> Option<DateTime<FixedOffset>> {
+        let (label, value) = match strip_label(string.trim()) {
+            Some((label, value)) => (label, value.trim_end()),
+            None => return self.parse(string),
+        };
+        let seconds = value.split_once('.').map_or(value, |(seconds, _)| seconds);
+        let number = is_digits(seconds.strip_prefix('-').unwrap_or(seconds));
+        let datetime = if number && is_epoch_label(label) {
+            self.parse(&format!("@{}", value))
+        } else if number {
+            // Only a compact date, never an epoch the label didn't ask for
+            compact_date(value).and_then(|date| self.parse(&date))
+        } else {
+            self.parse(value)
+        };
+        datetime.or_else(|| self.parse(string))
+    }
+
+    /// Parses a string that may lack parts of a date, like
+    /// [`parse_partial`](crate::parse_partial) does.
+    pub fn parse_partial(&self, string: &str) -> Option<PartialDate> {
+        let string = string.trim();
+        if let Some(partial) = components::year_month(string) {
+            let year = match partial {
+                PartialDate::Year(year) | PartialDate::YearMonth(year, _) => year,
+                PartialDate::Date(date) => date.year(),
+                PartialDate::DateTime(datetime) => datetime.year(),
+            };
+            return (self.years.0..=self.years.1)
+                .contains(&year)
+                .then_some(partial);
+        }
+        let datetime = self.parse(string)?;
+        // An input without a time of day follows `date_only_time`, one with
+        // a time does not
+        let other = match self.date_only_time == NaiveTime::MIN {
+            true => NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            false => NaiveTime::MIN,
+        };
+        match self.date_only_time(other).parse(string) {
+            Some(moved) if moved != datetime => Some(PartialDate::Date(datetime.date_naive())),
+            _ => Some(PartialDate::DateTime(datetime)),
+        }
+    }
+
+    /// Parses a string and tells how the zone was written, like
+    /// [`parse_date_components`](crate::parse_date_components) does.
+    pub fn parse_components<'a>(&self, string: &'a str) -> Option<DateComponents<'a>> {
+        let datetime = self.parse(string)?;
+        let offset = components::parsed_offset_token(string, *datetime.offset());
+        Some(DateComponents { datetime, offset })
+    }
+
+    /// Parses a string and counts the format families attempted, like
+    /// [`parse_date_debug`](crate::parse_date_debug) does.
+    pub fn parse_debug(&self, string: &str) -> (Option<DateTime<FixedOffset>>, usize) {
+        let mut attempts = Attempts {
+            counting: true,
+            ..Attempts::default()
+        };
+        let datetime = parse(self, string, &mut attempts);
+        (datetime, attempts.families)
+    }
+
+    /// Parses a string using multiple formats, reporting why the input was
+    /// rejected in the way [`try_parse_date`](crate::try_parse_date) does.
+    pub fn try_parse(&self, string: &str) -> Result<DateTime<FixedOffset>, ParseDateError> {
+        try_parse(self, string)
+    }
+}
@@ -0,0 +1,63 @@
+//! Shorthands for writing the dates a test expects `parse_date` to return.
+//!
+//! Available with the `test-util` feature.
+//!
+//! ```rust
+//! use diligent_date_parser::parse_date;
+//! use diligent_date_parser::test_util::at;
+//!
+//! assert_eq!(
+//!     parse_date("2016-04-21T08:30:00+01:00"),
+//!     Some(at(2016, 4, 21, 8, 30, 0, 3600)),
+//! );
+//! ```
+
+use chrono::prelude::*;
+
+/// An offset of the given number of seconds east of UTC.
+///
+/// # Panics
+///
+/// If the offset is a day or more.
+pub const fn offset(seconds: i32) -> FixedOffset {
+    match FixedOffset::east_opt(seconds) {
+        Some(offset) => offset,
+        None => panic!("offset out of range"),
+    }
+}
+
+/// A date and time in the offset of the given number of seconds east of UTC.
+///
+/// # Panics
+///
+/// If the date, the time or the offset is invalid.
+pub fn at(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    offset_seconds: i32,
+) -> DateTime<FixedOffset> {
+    offset(offset_seconds)
+        .with_ymd_and_hms(year, month, day, hour, minute, second)
+        .single()
+        .expect("invalid date or time")
+}
+
+/// A date and time in UTC, as `parse_date` returns for inputs without zone.
+///
+/// # Panics
+///
+/// If the date or the time is invalid.
+pub fn utc(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+) -> DateTime<FixedOffset> {
+    at(year, month, day, hour, minute, second, 0)
+}
@@ -0,0 +1,99 @@
+use chrono::prelude::*;
+use chrono::Duration;
+
+/// How the weeks of `2016-W15-5` style dates are numbered, see
+/// [`DateParser::with_week_numbering`](crate::DateParser::with_week_numbering).
+/// More numberings may be added.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WeekNumbering {
+    /// ISO 8601: weeks start on Monday and week 1 is the one containing
+    /// January 4th. Day 1 is Monday, day 7 is Sunday.
+    #[default]
+    Iso,
+    /// The epidemiological weeks of the US CDC (MMWR weeks): like ISO 8601,
+    /// but weeks start on Sunday. Day 1 is Sunday, day 7 is Saturday.
+    UsCdc,
+    /// Weeks of a fiscal year. Fiscal year `YYYY` starts on the month and
+    /// day of the given date in calendar year `YYYY`, and so does its week
+    /// 1; the year of the date is ignored. Day 1 is the weekday the fiscal
+    /// year starts on. A start of February 29th falls on February 28th in
+    /// years without one.
+    FiscalStarting(NaiveDate),
+}
+
+impl WeekNumbering {
+    /// The first day of week 1 of a year.
+    fn year_start(self, year: i32) -> Option<NaiveDate> {
+        let week_start = match self {
+            WeekNumbering::Iso => Weekday::Mon,
+            WeekNumbering::UsCdc => Weekday::Sun,
+            WeekNumbering::FiscalStarting(start) => {
+                let (month, day) = (start.month(), start.day());
+                return NaiveDate::from_ymd_opt(year, month, day)
+                    .or_else(|| NaiveDate::from_ymd_opt(year, month, day - 1));
+            }
+        };
+        let january_4th = NaiveDate::from_ymd_opt(year, 1, 4)?;
+        let days = january_4th.weekday().days_since(week_start);
+        january_4th.checked_sub_signed(Duration::days(days.into()))
+    }
+
+    /// Resolves day `day` (1 to 7) of week `week` of a year.
+    pub(crate) fn date(self, year: i32, week: u32, day: u32) -> Option<NaiveDate> {
+        if week == 0 || !(1..=7).contains(&day) {
+            return None;
+        }
+        let offset = Duration::days(i64::from((week - 1) * 7 + day - 1));
+        let date = self.year_start(year)?.checked_add_signed(offset)?;
+        (date < self.year_start(year + 1)?).then_some(date)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    #[test]
+    fn test_iso() {
+        let iso = WeekNumbering::Iso;
+        assert_eq!(iso.date(2016, 15, 5), date(2016, 4, 15));
+        assert_eq!(iso.date(2016, 1, 1), date(2016, 1, 4));
+        assert_eq!(iso.date(2015, 53, 7), date(2016, 1, 3));
+        assert_eq!(iso.date(2016, 53, 1), None);
+        assert_eq!(iso.date(2016, 0, 1), None);
+        assert_eq!(iso.date(2016, 15, 8), None);
+    }
+
+    #[test]
+    fn test_us_cdc() {
+        let cdc = WeekNumbering::UsCdc;
+        assert_eq!(cdc.date(2016, 1, 1), date(2016, 1, 3));
+        assert_eq!(cdc.date(2016, 15, 6), date(2016, 4, 15));
+        assert_eq!(cdc.date(2015, 1, 1), date(2015, 1, 4));
+        assert_eq!(cdc.date(2014, 53, 7), date(2015, 1, 3));
+    }
+
+    #[test]
+    fn test_fiscal() {
+        let fiscal = WeekNumbering::FiscalStarting(date(2015, 10, 1).unwrap());
+        assert_eq!(fiscal.date(2016, 1, 1), date(2016, 10, 1));
+        assert_eq!(fiscal.date(2016, 2, 3), date(2016, 10, 10));
+        assert_eq!(fiscal.date(2016, 53, 1), date(2017, 9, 30));
+        assert_eq!(fiscal.date(2016, 53, 2), None);
+    }
+
+    #[test]
+    fn test_fiscal_leap_day() {
+        let fiscal = WeekNumbering::FiscalStarting(date(2012, 2, 29).unwrap());
+        assert_eq!(fiscal.date(2016, 1, 1), date(2016, 2, 29));
+        assert_eq!(fiscal.date(2017, 1, 1), date(2017, 2, 28));
+        assert_eq!(fiscal.date(2016, 52, 7), date(2017, 2, 26));
+        assert_eq!(fiscal.date(2016, 53, 1), date(2017, 2, 27));
+        assert_eq!(fiscal.date(2016, 53, 2), None);
+    }
+}
@@ -0,0 +1,221 @@
+use chrono::FixedOffset;
+
+/// Parses the `+5`, `-3:30` or `+0530` part of a `GMT+5` style zone.
+pub(crate) fn signed_offset(string: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match string.as_bytes().first()? {
+        b'+' => (1, &string[1..]),
+        b'-' => (-1, &string[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if rest.len() == 4 => (rest.get(..2)?, rest.get(2..)?),
+        None => (rest, "00"),
+    };
+    if !(1..=2).contains(&hours.len()) || minutes.len() != 2 {
+        return None;
+    }
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes > 59 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Common zone abbreviations and their offsets in hours and minutes. Ambiguous ones,
+/// like `IST`, are left out; `CST` is the North American one, as in RFC2822.
+const ABBREVIATIONS: [(&str, i32, i32); 38] = [
+    ("UT", 0, 0),
+    ("UTC", 0, 0),
+    ("GMT", 0, 0),
+    ("WET", 0, 0),
+    ("WEST", 1, 0),
+    ("BST", 1, 0),
+    ("CET", 1, 0),
+    ("CEST", 2, 0),
+    ("MET", 1, 0),
+    ("MEST", 2, 0),
+    ("EET", 2, 0),
+    ("EEST", 3, 0),
+    ("MSK", 3, 0),
+    ("WAT", 1, 0),
+    ("CAT", 2, 0),
+    ("EAT", 3, 0),
+    ("SAST", 2, 0),
+    ("PKT", 5, 0),
+    ("HKT", 8, 0),
+    ("AWST", 8, 0),
+    ("JST", 9, 0),
+    ("KST", 9, 0),
+    ("ACST", 9, 30),
+    ("AEST", 10, 0),
+    ("AEDT", 11, 0),
+    ("NZST", 12, 0),
+    ("NZDT", 13, 0),
+    ("HST", -10, 0),
+    ("AKST", -9, 0),
+    ("AKDT", -8, 0),
+    ("PST", -8, 0),
+    ("PDT", -7, 0),
+    ("MST", -7, 0),
+    ("MDT", -6, 0),
+    ("CST", -6, 0),
+    ("CDT", -5, 0),
+    ("EST", -5, 0),
+    ("EDT", -4, 0),
+];
+
+/// Spelled out zone names as Windows and Office write them, with their
+/// offsets in hours and minutes. Most are Windows time zone IDs, which name
+/// the standard time even for zones observing daylight saving time; the
+/// North American daylight time names are added as they show up in exports.
+const WINDOWS_ZONES: [(&str, i32, i32); 32] = [
+    ("Coordinated Universal Time", 0, 0),
+    ("UTC", 0, 0),
+    ("GMT Standard Time", 0, 0),
+    ("Greenwich Standard Time", 0, 0),
+    ("W. Europe Standard Time", 1, 0),
+    ("Central Europe Standard Time", 1, 0),
+    ("Central European Standard Time", 1, 0),
+    ("Romance Standard Time", 1, 0),
+    ("E. Europe Standard Time", 2, 0),
+    ("FLE Standard Time", 2, 0),
+    ("GTB Standard Time", 2, 0),
+    ("Russian Standard Time", 3, 0),
+    ("India Standard Time", 5, 30),
+    ("China Standard Time", 8, 0),
+    ("Tokyo Standard Time", 9, 0),
+    ("Korea Standard Time", 9, 0),
+    ("AUS Central Standard Time", 9, 30),
+    ("AUS Eastern Standard Time", 10, 0),
+    ("E. Australia Standard Time", 10, 0),
+    ("New Zealand Standard Time", 12, 0),
+    ("Hawaiian Standard Time", -10, 0),
+    ("Alaskan Standard Time", -9, 0),
+    ("Alaskan Daylight Time", -8, 0),
+    ("Pacific Standard Time", -8, 0),
+    ("Pacific Daylight Time", -7, 0),
+    ("Mountain Standard Time", -7, 0),
+    ("Mountain Daylight Time", -6, 0),
+    ("Central Standard Time", -6, 0),
+    ("Central Daylight Time", -5, 0),
+    ("Eastern Standard Time", -5, 0),
+    ("Eastern Daylight Time", -4, 0),
+    ("Atlantic Standard Time", -4, 0),
+];
+
+/// Splits a spelled out zone name, like `Eastern Standard Time`, off the
+/// end of a string and resolves it. Case is ignored.
+pub(crate) fn trailing_windows_zone(string: &str) -> Option<(&str, FixedOffset)> {
+    WINDOWS_ZONES.iter().find_map(|&(name, hours, minutes)| {
+        let start = string.len().checked_sub(name.len())?;
+        let zone = string.get(start..)?;
+        let rest = string[..start].strip_suffix(' ')?;
+        if !zone.eq_ignore_ascii_case(name) {
+            return None;
+        }
+        Some((rest, FixedOffset::east_opt(hours * 3600 + minutes * 60)?))
+    })
+}
+
+/// Resolves a zone name to its offset.
+///
+/// Abbreviations such as `PST` or `CEST` are looked up in a fixed table
+/// (`EST` and `EDT` included), which knows nothing of daylight saving time:
+/// `PST` is always `-08:00`, whatever the date.
+///
+/// `GMT+5` and `UTC+5` are read the way people mean them: five hours ahead
+/// of UTC. IANA's `Etc/GMT+5`, however, follows the POSIX convention where
+/// the sign is inverted, so it is five hours *behind* UTC (`-05:00`).
+pub(crate) fn zone_offset(name: &str) -> Option<FixedOffset> {
+    if let Some(&(_, hours, minutes)) = ABBREVIATIONS.iter().find(|(zone, ..)| *zone == name) {
+        return FixedOffset::east_opt(hours * 3600 + minutes * 60);
+    }
+    if let Some(hours) = name.strip_prefix("Etc/GMT") {
+        if hours.is_empty() || hours == "+0" || hours == "-0" {
+            return FixedOffset::east_opt(0);
+        }
+        let offset = signed_offset(hours).filter(|_| !hours.contains(':'))?;
+        if offset.local_minus_utc() % 3600 != 0 {
+            return None;
+        }
+        return FixedOffset::east_opt(-offset.local_minus_utc());
+    }
+    let rest = name
+        .strip_prefix("GMT")
+        .or_else(|| name.strip_prefix("UTC"))?;
+    signed_offset(rest)
+}
+
+/// Whether a word names a zone understood by [`zone_offset`].
+pub(crate) fn is_zone_name(name: &str) -> bool {
+    zone_offset(name).is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hours(hours: i32) -> Option<FixedOffset> {
+        FixedOffset::east_opt(hours * 3600)
+    }
+
+    #[test]
+    fn test_zone_offset() {
+        assert_eq!(zone_offset("GMT+5"), hours(5));
+        assert_eq!(zone_offset("UTC-3"), hours(-3));
+        assert_eq!(zone_offset("GMT+05:30"), FixedOffset::east_opt(19800));
+        assert_eq!(zone_offset("GMT+0530"), FixedOffset::east_opt(19800));
+        assert_eq!(zone_offset("GMT+15"), None);
+        assert_eq!(zone_offset("GMT+5:3"), None);
+        assert_eq!(zone_offset("GMT+5àZ"), None);
+        assert_eq!(zone_offset("GMT+àZ"), None);
+        assert_eq!(zone_offset("GMT"), hours(0));
+        assert_eq!(zone_offset("PST"), hours(-8));
+        assert_eq!(zone_offset("CEST"), hours(2));
+        assert_eq!(zone_offset("ACST"), FixedOffset::east_opt(34200));
+        assert_eq!(zone_offset("pst"), None);
+        assert_eq!(zone_offset("IST"), None);
+        assert_eq!(zone_offset("Etc/GMT+5"), hours(-5));
+        assert_eq!(zone_offset("Etc/GMT-14"), hours(14));
+        assert_eq!(zone_offset("Etc/GMT"), hours(0));
+        assert_eq!(zone_offset("Etc/GMT-0"), hours(0));
+        assert_eq!(zone_offset("Etc/GMT+05:30"), None);
+        assert_eq!(zone_offset("Etc/GMT+0530"), None);
+        assert_eq!(zone_offset("America/New_York"), None);
+    }
+
+    #[test]
+    fn test_abbreviations_are_not_dates() {
+        for (zone, ..) in ABBREVIATIONS {
+            assert!(zone.parse::<chrono::Month>().is_err(), "{}", zone);
+            assert!(zone.parse::<chrono::Weekday>().is_err(), "{}", zone);
+        }
+    }
+
+    #[test]
+    fn test_trailing_windows_zone() {
+        assert_eq!(
+            trailing_windows_zone("8:30:00 AM Eastern Standard Time"),
+            Some(("8:30:00 AM", FixedOffset::west_opt(5 * 3600).unwrap()))
+        );
+        assert_eq!(
+            trailing_windows_zone("8:30 pacific daylight time"),
+            Some(("8:30", FixedOffset::west_opt(7 * 3600).unwrap()))
+        );
+        assert_eq!(
+            trailing_windows_zone("08:30 India Standard Time"),
+            FixedOffset::east_opt(19800).map(|offset| ("08:30", offset))
+        );
+        assert_eq!(trailing_windows_zone("Eastern Standard Time"), None);
+        assert_eq!(trailing_windows_zone("8:30 Martian Standard Time"), None);
+    }
+}
@@ -0,0 +1,30 @@
+use diligent_date_parser::{parse_date, DateTime};
+
+const DATES: &str = include_str!("fixtures/dates.tsv");
+
+#[test]
+fn test_fixture_dates() {
+    let mut failures = Vec::new();
+    for (number, line) in DATES.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (input, expected) = line
+            .split_once('\t')
+            .unwrap_or_else(|| panic!("line {}: missing tab separator", number + 1));
+        let expected = DateTime::parse_from_rfc3339(expected.trim())
+            .unwrap_or_else(|e| panic!("line {}: invalid expected value: {}", number + 1, e));
+        let actual = parse_date(input);
+        if actual != Some(expected) || actual.map(|d| *d.offset()) != Some(*expected.offset()) {
+            failures.push(format!(
+                "line {}: {:?} parsed as {:?}, expected {:?}",
+                number + 1,
+                input,
+                actual,
+                expected
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
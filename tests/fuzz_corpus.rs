@@ -0,0 +1,23 @@
+//! Replays the fuzzing corpus, which keeps inputs that once crashed the
+//! parser or reached unusual paths, to make sure none of them panics.
+
+use diligent_date_parser::{parse_date, parse_time, try_parse_date, DateParser};
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn test_fuzz_corpus() {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus/parse_date");
+    let english = DateParser::new().allow_english(true);
+    let mut count = 0;
+    for entry in fs::read_dir(corpus).unwrap() {
+        let data = fs::read(entry.unwrap().path()).unwrap();
+        let string = String::from_utf8_lossy(&data);
+        let _ = parse_date(&string);
+        let _ = try_parse_date(&string);
+        let _ = english.parse(&string);
+        let _ = parse_time(&string);
+        count += 1;
+    }
+    assert!(count > 0, "corpus is empty");
+}
@@ -0,0 +1,16 @@
+use diligent_date_parser::parse_date;
+
+#[test]
+fn test_space_separated_minutes_with_zone_name() {
+    let datetime = parse_date("2016-04-21 08:30 EST").unwrap();
+    assert_eq!(Some(datetime), parse_date("2016-04-21T08:30:00-05:00"));
+    assert_eq!(datetime.offset().local_minus_utc(), -5 * 3600);
+    assert_eq!(
+        parse_date("2016-04-21T08:30 PST"),
+        parse_date("2016-04-21T08:30:00-08:00")
+    );
+    assert_eq!(
+        parse_date("2016-04-21 08:30 +02:00"),
+        parse_date("2016-04-21T08:30:00+02:00")
+    );
+}
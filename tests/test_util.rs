@@ -0,0 +1,13 @@
+#![cfg(feature = "test-util")]
+
+use diligent_date_parser::parse_date;
+use diligent_date_parser::test_util::{at, offset, utc};
+
+#[test]
+fn test_expected_values() {
+    const CET: diligent_date_parser::FixedOffset = offset(3600);
+    let expected = at(2016, 4, 21, 8, 30, 0, 3600);
+    assert_eq!(parse_date("2016-04-21T08:30:00+01:00"), Some(expected));
+    assert_eq!(expected.offset(), &CET);
+    assert_eq!(parse_date("2016-04-21"), Some(utc(2016, 4, 21, 0, 0, 0)));
+}
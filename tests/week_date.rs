@@ -0,0 +1,13 @@
+use diligent_date_parser::parse_date;
+
+#[test]
+fn test_week_date_with_time_and_offset() {
+    let week_date = parse_date("2016-W15-5T08:30:00+02:00").unwrap();
+    let calendar_date = parse_date("2016-04-15T08:30:00+02:00").unwrap();
+    assert_eq!(week_date, calendar_date);
+    assert_eq!(week_date.offset(), calendar_date.offset());
+    assert_eq!(
+        parse_date("2016-W15-5T08:30:00-05:30"),
+        parse_date("2016-04-15T14:00:00Z")
+    );
+}